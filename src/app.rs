@@ -1,13 +1,29 @@
 use cpal::traits::{DeviceTrait, HostTrait};
 use eframe::egui;
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Arc, Mutex,
+    mpsc, Arc, Mutex,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::audio::start_audio_and_transcription;
-use crate::settings::{DisplayMode, Settings, SOURCE_LANGUAGES, TARGET_LANGUAGES};
+use crate::audio::{
+    list_audio_hosts, recover_poison, select_host, start_audio_and_transcription, ApiError,
+    ApiFailure, WorkerStatus,
+};
+use crate::control::start_control_api;
+use crate::i18n::{tr, UI_LANGUAGES};
+use crate::pipe::start_caption_pipe;
+use crate::platform::set_visible_on_all_workspaces;
+use crate::schedule::{schedule_status, start_scheduler};
+use crate::settings::{
+    language_name, prune_session_files, BarPosition, CasingMode, ClockMode, ControlsPosition,
+    Corner, DisplayMode, LogFlushMode, NumericPhraseMode, QuoteStripping, RepeatHandling,
+    ResampleQuality, Settings, SubtitleContent, SubtitleFormat, TermPair, UploadMode, AUTO_LANGUAGE,
+    DEFAULT_TRANSCRIBE_MODEL, DEFAULT_TRANSLATION_PROMPT, SILENCE_THRESHOLD_MAX,
+    SILENCE_THRESHOLD_MIN, SILENCE_THRESHOLD_STEP, SOURCE_LANGUAGES, TARGET_LANGUAGES,
+};
 
 fn setup_korean_fonts(ctx: &egui::Context) {
     let mut fonts = egui::FontDefinitions::default();
@@ -46,6 +62,11 @@ fn load_icon(ctx: &egui::Context, name: &str, png_bytes: &[u8]) -> egui::Texture
 }
 
 const RESIZE_BORDER: f32 = 8.0;
+const STATUS_BAR_HEIGHT: f32 = 22.0;
+const TOAST_SECS: f32 = 1.5;
+const STARTUP_HIDDEN_FRAMES: u32 = 3;
+const OPACITY_EASING: f32 = 0.1; // per frame, for reactive opacity
+const STARTUP_MAX_HIDDEN_FRAMES: u32 = 30;
 
 fn detect_resize_direction(ctx: &egui::Context) -> Option<egui::ResizeDirection> {
     let rect = ctx.input(|i| i.screen_rect());
@@ -69,8 +90,206 @@ fn detect_resize_direction(ctx: &egui::Context) -> Option<egui::ResizeDirection>
     }
 }
 
-fn list_input_devices() -> Vec<String> {
-    let host = cpal::default_host();
+// Rect for an overlay control `offset` px from the chosen corner along the
+// control row (the close button sits at offset 0)
+fn control_rect(
+    panel: egui::Rect,
+    position: ControlsPosition,
+    offset: f32,
+    width: f32,
+) -> egui::Rect {
+    let x = match position {
+        ControlsPosition::TopLeft => panel.left() + offset,
+        _ => panel.right() - offset - width,
+    };
+    let y = match position {
+        ControlsPosition::BottomRight => panel.bottom() - 32.0,
+        _ => panel.top(),
+    };
+    egui::Rect::from_min_size(egui::pos2(x, y), egui::vec2(width, 32.0))
+}
+
+// Formats a clock value, falling back to HH:MM:SS when the user's format
+// string is invalid (chrono panics on `to_string` in that case)
+fn format_clock(t: chrono::NaiveDateTime, fmt: &str) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+    if write!(out, "{}", t.format(fmt)).is_err() {
+        out.clear();
+        let _ = write!(out, "{}", t.format("%H:%M:%S"));
+    }
+    out
+}
+
+// Wraps a caption in the configured prefix/suffix for display only; `{lang}`
+// becomes the code of the language being shown
+fn decorate_caption(text: &str, settings: &Settings) -> String {
+    if settings.caption_prefix.is_empty() && settings.caption_suffix.is_empty() {
+        return text.to_string();
+    }
+    let lang = if settings.target_language.is_empty() {
+        &settings.language
+    } else {
+        &settings.target_language
+    };
+    format!(
+        "{}{text}{}",
+        settings.caption_prefix.replace("{lang}", lang),
+        settings.caption_suffix.replace("{lang}", lang)
+    )
+}
+
+// Banner text for a failed API call, e.g. "⚠ Transcription failed (401)"
+fn api_error_text(lang: &str, error: ApiError) -> String {
+    let what = match error.purpose {
+        "Translation" => tr(lang, "Translation failed"),
+        "Refine" => tr(lang, "Refine failed"),
+        "Cleanup" => tr(lang, "Cleanup failed"),
+        _ => tr(lang, "Transcription failed"),
+    };
+    let why = match error.failure {
+        ApiFailure::Http(code) => code.to_string(),
+        ApiFailure::Timeout => tr(lang, "timeout").to_string(),
+        ApiFailure::Connection => tr(lang, "connection error").to_string(),
+        ApiFailure::Request => tr(lang, "request error").to_string(),
+        ApiFailure::InvalidResponse => tr(lang, "invalid response").to_string(),
+    };
+    format!("⚠ {what} ({why})")
+}
+
+// Horizontal input meter on the same log scale as the threshold slider, with
+// the threshold marked; green when the input counts as speech, red otherwise
+fn level_meter(ui: &mut egui::Ui, level: f32, threshold: f32) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(220.0, 12.0), egui::Sense::hover());
+    let (min, max) = (SILENCE_THRESHOLD_MIN.ln(), SILENCE_THRESHOLD_MAX.ln());
+    let x_for = |value: f32| {
+        let t = ((value.max(f32::MIN_POSITIVE).ln() - min) / (max - min)).clamp(0.0, 1.0);
+        rect.left() + t * rect.width()
+    };
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, egui::Color32::from_gray(40));
+    let bar = egui::Rect::from_min_max(rect.min, egui::pos2(x_for(level), rect.bottom()));
+    let color = if level > threshold {
+        egui::Color32::from_rgb(80, 200, 80)
+    } else {
+        egui::Color32::from_rgb(200, 80, 80)
+    };
+    painter.rect_filled(bar, 2.0, color);
+    let marker = x_for(threshold);
+    painter.line_segment(
+        [egui::pos2(marker, rect.top() - 2.0), egui::pos2(marker, rect.bottom() + 2.0)],
+        egui::Stroke::new(2.0, egui::Color32::WHITE),
+    );
+    response
+}
+
+// Parses a shortcut like "Ctrl+Shift+D" or "F2" (case-insensitive modifiers)
+fn parse_hotkey(text: &str) -> Option<egui::KeyboardShortcut> {
+    let mut modifiers = egui::Modifiers::NONE;
+    let mut key = None;
+    for part in text.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= egui::Modifiers::CTRL,
+            "shift" => modifiers |= egui::Modifiers::SHIFT,
+            "alt" | "option" => modifiers |= egui::Modifiers::ALT,
+            "cmd" | "command" => modifiers |= egui::Modifiers::COMMAND,
+            _ => key = Some(egui::Key::from_name(part)?),
+        }
+    }
+    Some(egui::KeyboardShortcut::new(modifiers, key?))
+}
+
+fn hotkey_pressed(ctx: &egui::Context, binding: &str) -> bool {
+    parse_hotkey(binding).is_some_and(|shortcut| ctx.input_mut(|i| i.consume_shortcut(&shortcut)))
+}
+
+// System-wide shortcut, so it works while another app has focus; empty means
+// disabled, and a binding another app already holds is skipped
+fn register_global_hotkey(manager: &GlobalHotKeyManager, binding: &str) -> Option<HotKey> {
+    if binding.trim().is_empty() {
+        return None;
+    }
+    let hotkey = match binding.parse::<HotKey>() {
+        Ok(hotkey) => hotkey,
+        Err(e) => {
+            eprintln!("Invalid global hotkey '{binding}': {e}");
+            return None;
+        }
+    };
+    if let Err(e) = manager.register(hotkey) {
+        eprintln!("Could not register global hotkey '{binding}': {e}");
+        return None;
+    }
+    Some(hotkey)
+}
+
+// A global hotkey as bound in settings, and what was registered for it
+#[derive(Default)]
+struct GlobalBinding {
+    binding: String,
+    hotkey: Option<HotKey>,
+}
+
+impl GlobalBinding {
+    // Re-registers after the binding was changed in settings
+    fn sync(&mut self, manager: &GlobalHotKeyManager, binding: &str) {
+        if self.binding == binding {
+            return;
+        }
+        if let Some(old) = self.hotkey.take() {
+            let _ = manager.unregister(old);
+        }
+        self.hotkey = register_global_hotkey(manager, binding);
+        self.binding = binding.to_string();
+    }
+
+    fn matches(&self, event: &GlobalHotKeyEvent) -> bool {
+        self.hotkey.is_some_and(|hotkey| hotkey.id() == event.id())
+    }
+}
+
+// Common paste mistakes in an API key field, as a translatable hint
+fn api_key_problem(key: &str) -> Option<&'static str> {
+    if key.is_empty() {
+        None
+    } else if key.trim() != key {
+        Some("Key has leading or trailing spaces")
+    } else if key.chars().any(char::is_whitespace) {
+        Some("Key contains spaces or line breaks")
+    } else if key.len() >= 2
+        && ((key.starts_with('"') && key.ends_with('"'))
+            || (key.starts_with('\'') && key.ends_with('\'')))
+    {
+        Some("Key is wrapped in quotes")
+    } else if key.to_ascii_lowercase().starts_with("bearer") {
+        Some("Remove the \"Bearer\" prefix; it is added automatically")
+    } else if !key.is_ascii() {
+        Some("Key contains non-ASCII characters")
+    } else {
+        None
+    }
+}
+
+// Masked key field with a reveal toggle and a warning for likely paste errors
+fn api_key_field(ui: &mut egui::Ui, lang: &str, key: &mut String, revealed: &mut bool) {
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(key)
+                    .desired_width(370.0)
+                    .password(!*revealed),
+            );
+            ui.toggle_value(revealed, "👁")
+                .on_hover_text(tr(lang, "Show key"));
+        });
+        if let Some(problem) = api_key_problem(key) {
+            ui.colored_label(egui::Color32::from_rgb(230, 160, 60), tr(lang, problem));
+        }
+    });
+}
+
+fn list_input_devices(host_name: &str) -> Vec<String> {
+    let host = select_host(host_name);
     host.input_devices()
         .map(|devs| {
             devs.filter_map(|d| d.name().ok())
@@ -84,21 +303,30 @@ pub struct App {
     settings: Arc<Mutex<Settings>>,
     running: Arc<AtomicBool>,
     session_active: Arc<AtomicBool>,
+    status: Arc<WorkerStatus>,
+    session_started: Option<Instant>,
+    shown_font_size: f32, // eased toward the fitted size when smoothing is on
+    activity: f32,        // 0 = silent, 1 = speaking, eased for reactive opacity
     positioned: bool,
+    startup_frames: u32, // frames drawn blank while hide_startup_frames is on
+    reference_ppp: Option<f32>, // monitor scale at startup, for lock_physical_size
+    pinned_all_workspaces: bool, // last value applied to the OS window
+    applied_passthrough: bool,   // likewise for mouse_passthrough
     show_settings: bool,
-    edit_api_url: String,
-    edit_api_key: String,
-    edit_threshold: f32,
-    edit_language: String,
-    edit_font_size: f32,
-    edit_chat_api_url: String,
-    edit_chat_api_key: String,
-    edit_chat_model: String,
-    edit_target_language: String,
-    edit_display_mode: DisplayMode,
-    edit_opacity: u8,
-    edit_input_device: String,
+    revealed_keys: [bool; 3], // transcribe, compare, chat key shown unmasked
+    warned_missing_target: bool, // Both mode without a target was already flagged
+    edit: Settings, // working copy edited in the settings window
+    toast: Option<(String, Instant)>,
+    revealed_caption: Option<String>, // caption whose translation was revealed
+    emphasized_caption: Option<String>, // caption flagged important via hotkey
+    audio_hosts: Vec<String>,
     input_devices: Vec<String>,
+    global_hotkeys: Option<GlobalHotKeyManager>, // hotkeys stay registered while alive
+    hotkey_events: mpsc::Receiver<GlobalHotKeyEvent>,
+    session_hotkey: GlobalBinding,
+    visibility_hotkey: GlobalBinding,
+    passthrough_global_hotkey: GlobalBinding,
+    overlay_hidden: bool, // by the visibility hotkey: drawn empty and click-through
     cog_icon: egui::TextureHandle,
     close_icon: egui::TextureHandle,
 }
@@ -115,32 +343,62 @@ impl App {
 
         let transcript = Arc::new(Mutex::new(String::new()));
         let running = Arc::new(AtomicBool::new(true));
-        let session_active = Arc::new(AtomicBool::new(false));
-
-        let edit_api_url = loaded.api_url.clone();
-        let edit_api_key = loaded.api_key.clone();
-        let edit_threshold = loaded.silence_threshold;
-        let edit_language = loaded.language.clone();
-        let edit_font_size = loaded.font_size;
-        let edit_chat_api_url = loaded.chat_api_url.clone();
-        let edit_chat_api_key = loaded.chat_api_key.clone();
-        let edit_chat_model = loaded.chat_model.clone();
-        let edit_target_language = loaded.target_language.clone();
-        let edit_display_mode = loaded.display_mode.clone();
-        let edit_opacity = loaded.opacity;
-        let edit_input_device = loaded.input_device.clone();
-
-        let input_devices = list_input_devices();
+        // Kiosk setups can start logging immediately; the VAD thread opens the
+        // session log on its first iteration since it starts out inactive
+        let session_active = Arc::new(AtomicBool::new(loaded.auto_start_session));
+        let status = Arc::new(WorkerStatus::default());
+
+        let audio_hosts = list_audio_hosts();
+        let input_devices = list_input_devices(&loaded.audio_host);
+
+        prune_session_files(loaded.max_session_files);
 
+        let edit = loaded.clone();
         let settings = Arc::new(Mutex::new(loaded));
 
+        if edit.control_api_enabled {
+            start_control_api(
+                edit.control_api_bind.clone(),
+                transcript.clone(),
+                settings.clone(),
+                session_active.clone(),
+                status.clone(),
+            );
+        }
+
+        if !edit.pipe_name.is_empty() {
+            start_caption_pipe(edit.pipe_name.clone(), transcript.clone());
+        }
+
+        start_scheduler(
+            settings.clone(),
+            session_active.clone(),
+            status.clone(),
+            transcript.clone(),
+        );
+
         start_audio_and_transcription(
             transcript.clone(),
             running.clone(),
             settings.clone(),
             session_active.clone(),
+            status.clone(),
         );
 
+        // Bindings are registered on the first frame and again whenever
+        // they change in settings
+        let global_hotkeys = GlobalHotKeyManager::new()
+            .inspect_err(|e| eprintln!("Global hotkeys unavailable: {e}"))
+            .ok();
+        // Forwarded with a repaint request, so a press is handled right away
+        // even when nothing else would wake the overlay for a while
+        let (hotkey_tx, hotkey_events) = mpsc::channel();
+        let repaint_ctx = cc.egui_ctx.clone();
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            let _ = hotkey_tx.send(event);
+            repaint_ctx.request_repaint();
+        }));
+
         let cog_icon = load_icon(
             &cc.egui_ctx,
             "cog",
@@ -157,30 +415,106 @@ impl App {
             settings,
             running,
             session_active,
+            status,
+            session_started: edit.auto_start_session.then(Instant::now),
+            shown_font_size: edit.font_size,
+            activity: 0.0,
             positioned: false,
+            startup_frames: 0,
+            reference_ppp: None,
+            pinned_all_workspaces: false,
+            applied_passthrough: false,
             show_settings: false,
-            edit_api_url,
-            edit_api_key,
-            edit_threshold,
-            edit_language,
-            edit_font_size,
-            edit_chat_api_url,
-            edit_chat_api_key,
-            edit_chat_model,
-            edit_target_language,
-            edit_display_mode,
-            edit_opacity,
-            edit_input_device,
+            revealed_keys: [false; 3],
+            warned_missing_target: false,
+            edit,
+            toast: None,
+            revealed_caption: None,
+            emphasized_caption: None,
+            audio_hosts,
             input_devices,
+            global_hotkeys,
+            hotkey_events,
+            session_hotkey: GlobalBinding::default(),
+            visibility_hotkey: GlobalBinding::default(),
+            passthrough_global_hotkey: GlobalBinding::default(),
+            overlay_hidden: false,
             cog_icon,
             close_icon,
         }
     }
 }
 
+impl App {
+    // Applies a hotkey change to the live settings and the working copy, and
+    // confirms it with a toast
+    fn toggle_passthrough(&mut self) {
+        let on = !self.edit.mouse_passthrough;
+        let lang = &self.edit.ui_language;
+        let state = tr(lang, if on { "On" } else { "Off" });
+        let toast = format!("{}: {state}", tr(lang, "Click-through"));
+        self.change_setting(|s| s.mouse_passthrough = on, toast);
+    }
+
+    fn change_setting(&mut self, change: impl Fn(&mut Settings), toast: String) {
+        let mut s = self.settings.lock().unwrap_or_else(recover_poison);
+        change(&mut s);
+        s.save();
+        drop(s);
+        change(&mut self.edit);
+        self.toast = Some((toast, Instant::now()));
+    }
+
+    // Asks the VAD thread to write out the recent-audio buffer
+    fn save_retro_audio(&mut self) {
+        self.status.save_retro.store(true, Ordering::Relaxed);
+        let toast = format!(
+            "{} ({} s)",
+            tr(&self.edit.ui_language, "Saved recent audio"),
+            self.edit.retro_buffer_secs
+        );
+        self.toast = Some((toast, Instant::now()));
+    }
+
+    // Saves the current session's captions wherever the user picks
+    fn export_session(&mut self) {
+        let lang = self.edit.ui_language.clone();
+        let text = self.status.session_text.lock().unwrap_or_else(recover_poison).join("\n\n");
+        let name = format!("session_{}.txt", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S"));
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(name)
+            .add_filter("Text", &["txt"])
+            .save_file()
+        else {
+            return;
+        };
+        let toast = match std::fs::write(&path, text + "\n") {
+            Ok(()) => tr(&lang, "Session exported").to_string(),
+            Err(e) => format!("{}: {e}", tr(&lang, "Export failed")),
+        };
+        self.toast = Some((toast, Instant::now()));
+    }
+
+    fn toggle_settings(&mut self) {
+        self.show_settings = !self.show_settings;
+        if self.show_settings {
+            self.edit = self.settings.lock().unwrap_or_else(recover_poison).clone();
+            self.input_devices = list_input_devices(&self.edit.audio_host);
+            self.revealed_keys = [false; 3];
+        }
+    }
+
+    // Starting or stopping clears whatever caption was left on screen
+    fn toggle_session(&mut self) {
+        let new_state = !self.session_active.load(Ordering::Relaxed);
+        self.status.set_session_active(&self.session_active, &self.transcript, new_state);
+    }
+}
+
 impl Drop for App {
     fn drop(&mut self) {
         self.running.store(false, Ordering::Relaxed);
+        self.status.stopping.store(true, Ordering::Relaxed);
     }
 }
 
@@ -189,20 +523,162 @@ impl eframe::App for App {
         [0.0, 0.0, 0.0, 0.0]
     }
 
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
         // Position at bottom of screen on first frame
-        if !self.positioned {
-            if let Some(monitor) = ctx.input(|i| i.viewport().monitor_size) {
-                let window_h = 500.0;
-                ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(
-                    egui::pos2(0.0, monitor.y - window_h),
-                ));
-                self.positioned = true;
+        if !self.positioned
+            && let Some(monitor) = ctx.input(|i| i.viewport().monitor_size)
+        {
+            let window_h = 500.0;
+            ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(
+                egui::pos2(0.0, monitor.y - window_h),
+            ));
+            self.positioned = true;
+        }
+
+        // Some GPUs show the window opaque for a frame or two before transparent
+        // compositing kicks in; draw nothing until positioned and settled (but
+        // don't wait forever if the monitor size never arrives)
+        let settling = self.startup_frames < STARTUP_HIDDEN_FRAMES
+            || (!self.positioned && self.startup_frames < STARTUP_MAX_HIDDEN_FRAMES);
+        if self.edit.hide_startup_frames && settling {
+            self.startup_frames += 1;
+            egui::CentralPanel::default()
+                .frame(egui::Frame::new().fill(egui::Color32::TRANSPARENT))
+                .show(ctx, |_| {});
+            ctx.request_repaint();
+            return;
+        }
+
+        // egui scales by each monitor's own factor; with the lock on, keep the
+        // pixels-per-point of the first monitor so captions keep their
+        // physical size when dragged to a monitor with a different DPI
+        if let Some(native) = ctx.input(|i| i.viewport().native_pixels_per_point) {
+            let reference = *self.reference_ppp.get_or_insert(native);
+            let zoom = if self.edit.lock_physical_size {
+                reference / native
+            } else {
+                1.0
+            };
+            if (ctx.zoom_factor() - zoom).abs() > f32::EPSILON {
+                ctx.set_zoom_factor(zoom);
+            }
+        }
+
+        if self.edit.all_workspaces != self.pinned_all_workspaces {
+            set_visible_on_all_workspaces(frame, self.edit.all_workspaces);
+            self.pinned_all_workspaces = self.edit.all_workspaces;
+        }
+        let passthrough = self.edit.mouse_passthrough || self.overlay_hidden;
+        if passthrough != self.applied_passthrough {
+            ctx.send_viewport_cmd(egui::ViewportCommand::MousePassthrough(passthrough));
+            self.applied_passthrough = passthrough;
+        }
+
+        // Settings and session state can also change outside this window
+        // (e.g. the control API), so follow the shared copy while not editing
+        if !self.show_settings {
+            self.edit = self.settings.lock().unwrap_or_else(recover_poison).clone();
+        }
+        self.status.release_caption(&self.transcript, &self.edit);
+        let is_active = self.session_active.load(Ordering::Relaxed);
+        if is_active != self.session_started.is_some() {
+            self.session_started = is_active.then(Instant::now);
+        }
+
+        // Global hotkeys, delivered whichever app has focus. Bindings being
+        // typed in the settings window apply once it is saved or closed
+        if let Some(manager) = &self.global_hotkeys
+            && !self.show_settings
+        {
+            self.session_hotkey.sync(manager, &self.edit.global_session_hotkey);
+            self.visibility_hotkey.sync(manager, &self.edit.global_visibility_hotkey);
+            self.passthrough_global_hotkey.sync(manager, &self.edit.global_passthrough_hotkey);
+        }
+        while let Ok(event) = self.hotkey_events.try_recv() {
+            if event.state() != HotKeyState::Pressed {
+                continue;
+            }
+            if self.session_hotkey.matches(&event) {
+                self.toggle_session();
+            } else if self.visibility_hotkey.matches(&event) {
+                self.overlay_hidden = !self.overlay_hidden;
+                // Next frame applies click-through for the new state
+                ctx.request_repaint();
+            } else if self.passthrough_global_hotkey.matches(&event) {
+                self.toggle_passthrough();
+                ctx.request_repaint();
+            }
+        }
+        // Hidden by the visibility hotkey counts the same as minimized
+        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+        let hidden = minimized || self.overlay_hidden;
+        self.status.window_hidden.store(hidden, Ordering::Relaxed);
+
+        // Not hidden with ViewportCommand::Visible: a hidden window gets no
+        // frames on some platforms, so the hotkey to show it again would
+        // never be handled. An empty click-through frame looks the same.
+        if self.overlay_hidden {
+            egui::CentralPanel::default()
+                .frame(egui::Frame::new().fill(egui::Color32::TRANSPARENT))
+                .show(ctx, |_| {});
+            return;
+        }
+
+        // Overlay hotkeys (only while the overlay window has focus)
+        if hotkey_pressed(ctx, &self.edit.settings_hotkey) {
+            self.toggle_settings();
+        }
+        if hotkey_pressed(ctx, &self.edit.close_hotkey) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+        }
+        // Only reachable if the overlay can get focus, which a tool window
+        // (skip_taskbar/all_workspaces) can't via Alt+Tab; the global
+        // click-through hotkey above is the way back in that case
+        if hotkey_pressed(ctx, &self.edit.passthrough_hotkey) {
+            self.toggle_passthrough();
+        }
+        if hotkey_pressed(ctx, &self.edit.display_mode_hotkey) {
+            let mode = self.edit.display_mode.next();
+            let lang = &self.edit.ui_language;
+            let toast = format!("{}: {}", tr(lang, "Display"), tr(lang, mode.label()));
+            self.change_setting(|s| s.display_mode = mode.clone(), toast);
+        }
+        if hotkey_pressed(ctx, &self.edit.model_cycle_hotkey)
+            && let Some(model) = self.edit.next_shortlisted_model()
+        {
+            let toast = format!("{}: {model}", tr(&self.edit.ui_language, "Model"));
+            self.change_setting(|s| s.chat_model = model.clone(), toast);
+        }
+        if hotkey_pressed(ctx, &self.edit.reveal_hotkey) {
+            let text = self.transcript.lock().unwrap_or_else(recover_poison).clone();
+            self.revealed_caption = Some(text);
+        }
+        if hotkey_pressed(ctx, &self.edit.save_retro_hotkey) && self.edit.retro_buffer_secs > 0 {
+            self.save_retro_audio();
+        }
+        if hotkey_pressed(ctx, &self.edit.emphasis_hotkey) {
+            let current = self.transcript.lock().unwrap_or_else(recover_poison).clone();
+            // Pressing again on the same phrase clears the emphasis
+            self.emphasized_caption =
+                (self.emphasized_caption.as_ref() != Some(&current)).then_some(current);
+        }
+        for (binding, factor) in [
+            (self.edit.threshold_up_hotkey.clone(), SILENCE_THRESHOLD_STEP),
+            (self.edit.threshold_down_hotkey.clone(), 1.0 / SILENCE_THRESHOLD_STEP),
+        ] {
+            if hotkey_pressed(ctx, &binding) {
+                let threshold = (self.edit.silence_threshold * factor)
+                    .clamp(SILENCE_THRESHOLD_MIN, SILENCE_THRESHOLD_MAX);
+                let toast = format!(
+                    "{}: {threshold:.4}",
+                    tr(&self.edit.ui_language, "Silence Threshold")
+                );
+                self.change_setting(|s| s.silence_threshold = threshold, toast);
             }
         }
 
-        // Edge resize detection
-        let resize_dir = detect_resize_direction(ctx);
+        // Edge resize detection, suspended while clicks pass through
+        let resize_dir = detect_resize_direction(ctx).filter(|_| !self.edit.mouse_passthrough);
         if let Some(dir) = resize_dir {
             ctx.set_cursor_icon(match dir {
                 egui::ResizeDirection::North | egui::ResizeDirection::South => {
@@ -226,25 +702,21 @@ impl eframe::App for App {
         // Settings window (separate OS window)
         if self.show_settings {
             let close_req = std::cell::Cell::new(false);
+            let export_req = std::cell::Cell::new(false);
+            let session_active = self.session_active.load(Ordering::Relaxed);
 
-            let edit_api_url = &mut self.edit_api_url;
-            let edit_api_key = &mut self.edit_api_key;
-            let edit_threshold = &mut self.edit_threshold;
-            let edit_language = &mut self.edit_language;
-            let edit_font_size = &mut self.edit_font_size;
-            let edit_chat_api_url = &mut self.edit_chat_api_url;
-            let edit_chat_api_key = &mut self.edit_chat_api_key;
-            let edit_chat_model = &mut self.edit_chat_model;
-            let edit_target_language = &mut self.edit_target_language;
-            let edit_display_mode = &mut self.edit_display_mode;
-            let edit_opacity = &mut self.edit_opacity;
-            let edit_input_device = &mut self.edit_input_device;
-            let input_devices = &self.input_devices;
+            let title = tr(&self.edit.ui_language, "LiveCapTran Settings");
+            let edit = &mut self.edit;
+            let audio_hosts = &self.audio_hosts;
+            let input_devices = &mut self.input_devices;
+            let status = &self.status;
+            let [transcribe_key_shown, compare_key_shown, chat_key_shown] =
+                &mut self.revealed_keys;
 
             ctx.show_viewport_immediate(
                 egui::ViewportId::from_hash_of("settings"),
                 egui::ViewportBuilder::default()
-                    .with_title("LiveCapTran Settings")
+                    .with_title(title)
                     .with_inner_size([550.0, 550.0])
                     .with_resizable(false)
                     .with_minimize_button(false)
@@ -254,161 +726,1496 @@ impl eframe::App for App {
                     if ctx.input(|i| i.viewport().close_requested()) {
                         close_req.set(true);
                     }
+                    let lang = edit.ui_language.clone();
                     egui::CentralPanel::default().show(ctx, |ui| {
-                        egui::Grid::new("settings_grid")
-                            .num_columns(2)
-                            .spacing([10.0, 8.0])
-                            .show(ui, |ui| {
-                                ui.label("Transcribe API URL:");
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_api_url)
-                                        .desired_width(400.0),
-                                );
-                                ui.end_row();
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            egui::Grid::new("settings_grid")
+                                .num_columns(2)
+                                .spacing([10.0, 8.0])
+                                .show(ui, |ui| {
+                                    ui.label(tr(&lang, "UI Language:"));
+                                    egui::ComboBox::from_id_salt("ui_language_combo")
+                                        .selected_text(language_name(UI_LANGUAGES, &edit.ui_language))
+                                        .show_ui(ui, |ui| {
+                                            for &(code, name) in UI_LANGUAGES {
+                                                ui.selectable_value(
+                                                    &mut edit.ui_language,
+                                                    code.to_string(),
+                                                    name,
+                                                );
+                                            }
+                                        });
+                                    ui.end_row();
 
-                                ui.label("Transcribe API Key:");
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_api_key)
-                                        .desired_width(400.0)
-                                        .password(true),
-                                );
-                                ui.end_row();
-
-                                ui.label("Language:");
-                                egui::ComboBox::from_id_salt("language_combo")
-                                    .selected_text(
-                                        SOURCE_LANGUAGES
-                                            .iter()
-                                            .find(|(code, _)| *code == edit_language.as_str())
-                                            .map(|(_, name)| *name)
-                                            .unwrap_or(edit_language),
+                                    ui.label(tr(&lang, "Transcribe API URL:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.api_url)
+                                            .desired_width(400.0),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Transcribe API Key:"));
+                                    api_key_field(ui, &lang, &mut edit.api_key, transcribe_key_shown);
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Transcribe Model:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.transcribe_model)
+                                            .desired_width(400.0)
+                                            .hint_text(DEFAULT_TRANSCRIBE_MODEL),
                                     )
-                                    .show_ui(ui, |ui| {
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "e.g. whisper-1 for OpenAI or Systran/faster-whisper-medium for faster-whisper-server. Empty uses large-v3",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Compare API URL:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.compare_api_url)
+                                            .desired_width(400.0)
+                                            .hint_text(tr(&lang, "Off")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Also sends each phrase to this endpoint and logs both results, labeled, in the session log. Only the primary is displayed",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Compare API Key:"));
+                                    api_key_field(ui, &lang, &mut edit.compare_api_key, compare_key_shown);
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Transcribe Timeout:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.transcribe_timeout_secs, 5..=120)
+                                            .suffix(" s"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Upload Filename:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.upload_filename)
+                                            .desired_width(400.0)
+                                            .hint_text("audio.wav"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Filename sent with the upload, for servers that route on it. The extension is always .wav to match the audio",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Upload As:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.upload_mode,
+                                            UploadMode::Multipart,
+                                            tr(&lang, "Multipart"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.upload_mode,
+                                            UploadMode::Base64Json,
+                                            tr(&lang, "Base64 JSON"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Base64 JSON posts {\"audio\": \"<base64 wav>\", \"model\", \"language\"} as application/json, for serverless endpoints that don't accept multipart. The payload is about a third larger",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Language:"));
+                                    egui::ComboBox::from_id_salt("language_combo")
+                                        .selected_text(
+                                            SOURCE_LANGUAGES
+                                                .iter()
+                                                .find(|(code, _)| *code == edit.language.as_str())
+                                                .map(|(_, name)| *name)
+                                                .unwrap_or(&edit.language),
+                                        )
+                                        .show_ui(ui, |ui| {
+                                            for &(code, name) in SOURCE_LANGUAGES {
+                                                ui.selectable_value(
+                                                    &mut edit.language,
+                                                    code.to_string(),
+                                                    name,
+                                                );
+                                            }
+                                        });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Candidate Languages:"));
+                                    ui.horizontal_wrapped(|ui| {
+                                        ui.set_max_width(400.0);
                                         for &(code, name) in SOURCE_LANGUAGES {
-                                            ui.selectable_value(
-                                                edit_language,
-                                                code.to_string(),
-                                                name,
-                                            );
+                                            if code == AUTO_LANGUAGE {
+                                                continue;
+                                            }
+                                            let mut selected =
+                                                edit.candidate_languages.iter().any(|c| c == code);
+                                            if ui.checkbox(&mut selected, name).changed() {
+                                                if selected {
+                                                    edit.candidate_languages.push(code.to_string());
+                                                } else {
+                                                    edit.candidate_languages.retain(|c| c != code);
+                                                }
+                                            }
                                         }
                                     });
-                                ui.end_row();
-
-                                ui.label("Silence Threshold:");
-                                ui.add(
-                                    egui::Slider::new(edit_threshold, 0.0005..=0.05)
-                                        .logarithmic(true),
-                                );
-                                ui.end_row();
+                                    ui.end_row();
 
-                                ui.label("Font Size:");
-                                ui.add(egui::Slider::new(edit_font_size, 20.0..=120.0));
-                                ui.end_row();
+                                    ui.label(tr(&lang, "Silence Threshold:"));
+                                    ui.add(
+                                        egui::Slider::new(
+                                            &mut edit.silence_threshold,
+                                            SILENCE_THRESHOLD_MIN..=SILENCE_THRESHOLD_MAX,
+                                        )
+                                            .logarithmic(true),
+                                    );
+                                    ui.end_row();
 
-                                ui.label("");
-                                ui.separator();
-                                ui.end_row();
+                                    ui.label(tr(&lang, "Input Level:"));
+                                    let level = status.input_level.load(Ordering::Relaxed);
+                                    let meter = level_meter(ui, f32::from_bits(level), edit.silence_threshold);
+                                    meter.on_hover_text(tr(
+                                        &lang,
+                                        "Live microphone level. Set the threshold (white mark) just above the level shown while nobody speaks",
+                                    ));
+                                    ui.end_row();
 
-                                ui.label("Chat API URL:");
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_chat_api_url)
-                                        .desired_width(400.0),
-                                );
-                                ui.end_row();
+                                    ui.label(tr(&lang, "End of Phrase:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.end_silence_ms, 200..=2000)
+                                            .suffix(" ms"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Pause that ends a phrase. Lower for fast speakers with short pauses, higher for slow deliberate speakers",
+                                    ));
+                                    ui.end_row();
 
-                                ui.label("Chat API Key:");
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_chat_api_key)
-                                        .desired_width(400.0)
-                                        .password(true),
-                                );
-                                ui.end_row();
+                                    ui.label(tr(&lang, "Max Phrase Length:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.max_phrase_secs, 5..=60)
+                                            .suffix(" s"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Speech without a pause is cut and sent after this long",
+                                    ));
+                                    ui.end_row();
 
-                                ui.label("Chat Model:");
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_chat_model)
-                                        .desired_width(400.0),
-                                );
-                                ui.end_row();
-
-                                ui.label("Translate To:");
-                                egui::ComboBox::from_id_salt("target_language_combo")
-                                    .selected_text(if edit_target_language.is_empty() {
-                                        "None"
-                                    } else {
-                                        TARGET_LANGUAGES
-                                            .iter()
-                                            .find(|(code, _)| {
-                                                *code == edit_target_language.as_str()
-                                            })
-                                            .map(|(_, name)| *name)
-                                            .unwrap_or(edit_target_language)
+                                    ui.label(tr(&lang, "Auto Gain:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.agc_enabled, "");
+                                        ui.add_enabled_ui(edit.agc_enabled, |ui| {
+                                            ui.add(
+                                                egui::Slider::new(&mut edit.agc_target, 0.05..=0.5)
+                                                    .text(tr(&lang, "level")),
+                                            );
+                                        });
                                     })
-                                    .show_ui(ui, |ui| {
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Evens out the input level as the speaker moves relative to the mic, before voice detection. Gain is held during silence so pauses aren't amplified into false triggers. Retune the silence threshold after turning this on",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Gain Timing:"));
+                                    ui.add_enabled_ui(edit.agc_enabled, |ui| {
+                                        ui.horizontal(|ui| {
+                                            ui.add(
+                                                egui::Slider::new(&mut edit.agc_attack_ms, 1..=200)
+                                                    .suffix(" ms")
+                                                    .text(tr(&lang, "attack")),
+                                            );
+                                            ui.add(
+                                                egui::Slider::new(&mut edit.agc_release_ms, 50..=5000)
+                                                    .suffix(" ms")
+                                                    .text(tr(&lang, "release")),
+                                            );
+                                        });
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Resampling:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.resample_quality,
+                                            ResampleQuality::Linear,
+                                            tr(&lang, "Linear"),
+                                        );
                                         ui.selectable_value(
-                                            edit_target_language,
-                                            String::new(),
-                                            "None",
+                                            &mut edit.resample_quality,
+                                            ResampleQuality::Sinc,
+                                            "Sinc",
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "How phrases are converted to 16 kHz for upload. Sinc is more faithful, which can help tonal languages, at a higher but still small CPU cost; Linear is enough on low-end machines",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Normalize Audio:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.normalize_audio, "");
+                                        ui.add_enabled(
+                                            edit.normalize_audio,
+                                            egui::Slider::new(&mut edit.normalize_target, 0.1..=1.0)
+                                                .text(tr(&lang, "peak")),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Boosts quiet phrases so their peak reaches the target before upload. Voice detection still uses the unprocessed input",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Font Size:"));
+                                    ui.add(egui::Slider::new(&mut edit.font_size, 20.0..=120.0));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Min Font Size:"));
+                                    ui.add(egui::Slider::new(&mut edit.min_font_size, 8.0..=120.0));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Placeholder:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.animate_placeholder, tr(&lang, "Animate dots every"));
+                                        ui.add_enabled(
+                                            edit.animate_placeholder,
+                                            egui::Slider::new(&mut edit.placeholder_step_ms, 100..=1500)
+                                                .suffix(" ms"),
                                         );
-                                        for &(code, name) in TARGET_LANGUAGES {
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Overflow:"));
+                                    ui.checkbox(
+                                        &mut edit.scroll_on_overflow,
+                                        tr(&lang, "Scroll to newest text instead of shrinking further"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Long Words:"));
+                                    ui.checkbox(
+                                        &mut edit.break_long_words,
+                                        tr(&lang, "Break anywhere instead of shrinking the font"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Lets long URLs and compound words wrap mid-token. Any word at the line edge may then be split",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Physical Size:"));
+                                    ui.checkbox(
+                                        &mut edit.lock_physical_size,
+                                        tr(&lang, "Keep size across monitors with different scaling"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Font Smoothing:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.smooth_font_size, tr(&lang, "Ease size changes"));
+                                        ui.add_enabled(
+                                            edit.smooth_font_size,
+                                            egui::Slider::new(&mut edit.font_smoothing, 0.05..=1.0),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label("");
+                                    ui.separator();
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Chat API URL:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.chat_api_url)
+                                            .desired_width(400.0),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Chat API Key:"));
+                                    api_key_field(ui, &lang, &mut edit.chat_api_key, chat_key_shown);
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Chat Model:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.chat_model)
+                                            .desired_width(400.0),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Model Shortlist:"));
+                                    ui.horizontal(|ui| {
+                                        let mut shortlist = edit.model_shortlist.join("\n");
+                                        if ui
+                                            .add(
+                                                egui::TextEdit::multiline(&mut shortlist)
+                                                    .desired_rows(2)
+                                                    .desired_width(250.0)
+                                                    .hint_text("gpt-4o\ngpt-4o-mini"),
+                                            )
+                                            .changed()
+                                        {
+                                            edit.model_shortlist =
+                                                shortlist.split('\n').map(str::to_string).collect();
+                                        }
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.model_cycle_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+M"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "One model per line. The key switches the chat model to the next one on the list during a session",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Refine Model:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.refine_model)
+                                            .desired_width(400.0)
+                                            .hint_text(tr(&lang, "Off")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "A second model reviews each translation against the source. Adds latency and cost",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Chat Timeout:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.chat_timeout_secs, 2..=120)
+                                            .suffix(" s"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Retries:"));
+                                    ui.add(egui::Slider::new(&mut edit.transient_retries, 0..=5))
+                                        .on_hover_text(tr(
+                                            &lang,
+                                            "Retries after a server error or dropped connection, waiting 200 ms, 400 ms, 800 ms, ... in between. 0 for the lowest latency",
+                                        ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Cleanup:"));
+                                    ui.checkbox(
+                                        &mut edit.cleanup_enabled,
+                                        tr(&lang, "Remove fillers and fix punctuation (adds latency)"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Cleanup Prompt:"));
+                                    ui.add_enabled(
+                                        edit.cleanup_enabled,
+                                        egui::TextEdit::multiline(&mut edit.cleanup_prompt)
+                                            .desired_width(400.0)
+                                            .desired_rows(3),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Translate To:"));
+                                    egui::ComboBox::from_id_salt("target_language_combo")
+                                        .selected_text(if edit.target_language.is_empty() {
+                                            tr(&lang, "None")
+                                        } else {
+                                            TARGET_LANGUAGES
+                                                .iter()
+                                                .find(|(code, _)| {
+                                                    *code == edit.target_language.as_str()
+                                                })
+                                                .map(|(_, name)| *name)
+                                                .unwrap_or(&edit.target_language)
+                                        })
+                                        .show_ui(ui, |ui| {
                                             ui.selectable_value(
-                                                edit_target_language,
-                                                code.to_string(),
-                                                name,
+                                                &mut edit.target_language,
+                                                String::new(),
+                                                tr(&lang, "None"),
                                             );
+                                            for &(code, name) in TARGET_LANGUAGES {
+                                                ui.selectable_value(
+                                                    &mut edit.target_language,
+                                                    code.to_string(),
+                                                    name,
+                                                );
+                                            }
+                                        });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Prompt Overrides:"));
+                                    ui.vertical(|ui| {
+                                        let mut removed = None;
+                                        for (code, prompt) in edit.translation_prompts.iter_mut() {
+                                            ui.horizontal(|ui| {
+                                                ui.label(language_name(TARGET_LANGUAGES, code));
+                                                if ui.small_button("✖").clicked() {
+                                                    removed = Some(code.clone());
+                                                }
+                                            });
+                                            ui.add(
+                                                egui::TextEdit::multiline(prompt)
+                                                    .desired_width(400.0)
+                                                    .desired_rows(3),
+                                            );
+                                        }
+                                        if let Some(code) = removed {
+                                            edit.translation_prompts.remove(&code);
+                                        }
+                                        egui::ComboBox::from_id_salt("prompt_override_combo")
+                                            .selected_text(tr(&lang, "Add for language…"))
+                                            .show_ui(ui, |ui| {
+                                                for &(code, name) in TARGET_LANGUAGES {
+                                                    if !edit.translation_prompts.contains_key(code)
+                                                        && ui.selectable_label(false, name).clicked()
+                                                    {
+                                                        edit.translation_prompts.insert(
+                                                            code.to_string(),
+                                                            DEFAULT_TRANSLATION_PROMPT.to_string(),
+                                                        );
+                                                    }
+                                                }
+                                            });
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Translation instructions used instead of the default when that language is the target. {target} is replaced with the language code",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Term Translations:"));
+                                    ui.vertical(|ui| {
+                                        let mut removed = None;
+                                        for (i, pair) in edit.term_pairs.iter_mut().enumerate() {
+                                            ui.horizontal(|ui| {
+                                                ui.add(
+                                                    egui::TextEdit::singleline(&mut pair.source)
+                                                        .desired_width(170.0)
+                                                        .hint_text(tr(&lang, "term")),
+                                                );
+                                                ui.label("→");
+                                                ui.add(
+                                                    egui::TextEdit::singleline(&mut pair.target)
+                                                        .desired_width(170.0)
+                                                        .hint_text(tr(&lang, "keep as is")),
+                                                );
+                                                if ui.small_button("✖").clicked() {
+                                                    removed = Some(i);
+                                                }
+                                            });
+                                        }
+                                        if let Some(i) = removed {
+                                            edit.term_pairs.remove(i);
+                                        }
+                                        if ui.button(tr(&lang, "Add term")).clicked() {
+                                            edit.term_pairs.push(TermPair::default());
                                         }
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "The translator is told to always translate each term this way. Leave the translation empty to keep a name or acronym untranslated",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Context:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.translation_context_pairs, 0..=20)
+                                            .suffix(tr(&lang, " phrases")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Recent phrases sent along with each translation. More keeps terms and pronouns consistent through a lecture; fewer avoids carrying stale context across topic jumps in a Q&A. 0 = no context. Cleared when a session starts",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "History:"));
+                                    ui.checkbox(
+                                        &mut edit.history_includes_translation,
+                                        tr(&lang, "Include previous translations as context"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Turn off if the model drifts when fed its own output; only the previous source text is sent then",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Casing:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.translation_casing,
+                                            CasingMode::Unchanged,
+                                            tr(&lang, "As translated"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.translation_casing,
+                                            CasingMode::SentenceCase,
+                                            tr(&lang, "Sentence case"),
+                                        );
                                     });
-                                ui.end_row();
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Strip Quotes:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.strip_quotes,
+                                            QuoteStripping::OuterOnly,
+                                            tr(&lang, "Outer pair"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.strip_quotes,
+                                            QuoteStripping::Always,
+                                            tr(&lang, "All"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.strip_quotes,
+                                            QuoteStripping::Never,
+                                            tr(&lang, "Never"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Whole Sentences:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(
+                                            &mut edit.translate_on_sentence,
+                                            tr(&lang, "Translate at sentence end, or after"),
+                                        );
+                                        ui.add_enabled(
+                                            edit.translate_on_sentence,
+                                            egui::Slider::new(&mut edit.sentence_timeout_ms, 1000..=15000)
+                                                .suffix(" ms"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Fragments are shown untranslated until the sentence is complete",
+                                    ));
+                                    ui.end_row();
 
-                                ui.label("Display:");
-                                ui.horizontal(|ui| {
-                                    ui.selectable_value(
-                                        edit_display_mode,
-                                        DisplayMode::Both,
-                                        "Both",
+                                    ui.label(tr(&lang, "Glossary:"));
+                                    ui.add(
+                                        egui::TextEdit::multiline(&mut edit.glossary)
+                                            .desired_width(400.0)
+                                            .desired_rows(3)
+                                            .hint_text("optogenetics, channelrhodopsin, GCaMP6"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Domain terms sent to the transcription API as a prompt and to the translator as spellings to keep. Translations are also corrected to these exact spellings (e.g. \"dna\" becomes \"DNA\")",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Display:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.display_mode,
+                                            DisplayMode::Both,
+                                            tr(&lang, "Both"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.display_mode,
+                                            DisplayMode::TranslationOnly,
+                                            tr(&lang, "Translation only"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    if edit.missing_translation_target() {
+                                        ui.label("");
+                                        ui.colored_label(
+                                            egui::Color32::from_rgb(230, 160, 60),
+                                            tr(&lang, "No target language is set, so only the source text is shown. Pick a target language to see translations"),
+                                        );
+                                        ui.end_row();
+                                    }
+
+                                    ui.label(tr(&lang, "Rolling Captions:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.rolling_phrases, 1..=10)
+                                            .suffix(tr(&lang, " phrases")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Keeps the last phrases on screen, newest at the bottom, so a reader who looked away can catch up. The font shrinks to fit them all. 1 = only the latest phrase",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Caption Prefix:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.caption_prefix)
+                                            .desired_width(200.0)
+                                            .hint_text("e.g. [{lang}] "),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Added around each caption on screen only, not in the log. {lang} becomes the displayed language code",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Caption Suffix:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.caption_suffix)
+                                            .desired_width(200.0),
                                     );
-                                    ui.selectable_value(
-                                        edit_display_mode,
-                                        DisplayMode::TranslationOnly,
-                                        "Translation only",
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Display Mode Key:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.display_mode_hotkey)
+                                            .desired_width(120.0)
+                                            .hint_text("e.g. Ctrl+D"),
+                                    )
+                                    .on_hover_text(
+                                        tr(&lang, "Cycles the display mode while the overlay has focus. Leave empty to disable."),
                                     );
-                                });
-                                ui.end_row();
+                                    ui.end_row();
 
-                                ui.label("");
-                                ui.separator();
-                                ui.end_row();
+                                    ui.label(tr(&lang, "Threshold Keys:"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.threshold_up_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+Up"),
+                                        );
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.threshold_down_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+Down"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Raise or lower the silence threshold by 20% during a session",
+                                    ));
+                                    ui.end_row();
 
-                                ui.label("Opacity:");
-                                let mut opacity_f32 = *edit_opacity as f32;
-                                if ui.add(egui::Slider::new(&mut opacity_f32, 0.0..=255.0)).changed() {
-                                    *edit_opacity = opacity_f32 as u8;
-                                }
-                                ui.end_row();
-
-                                ui.label("Input Device:");
-                                egui::ComboBox::from_id_salt("input_device_combo")
-                                    .selected_text(if edit_input_device.is_empty() {
-                                        "Default"
-                                    } else {
-                                        edit_input_device.as_str()
+                                    ui.label(tr(&lang, "Emphasis:"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.emphasis_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+E"),
+                                        );
+                                        ui.add(
+                                            egui::Slider::new(&mut edit.emphasis_scale, 1.0..=2.0)
+                                                .text(tr(&lang, "size")),
+                                        );
+                                        ui.color_edit_button_srgb(&mut edit.emphasis_color);
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "The key marks the current caption as important until the next phrase",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Session Text:"));
+                                    if ui
+                                        .add_enabled(
+                                            session_active,
+                                            egui::Button::new(tr(&lang, "Export…")),
+                                        )
+                                        .on_hover_text(tr(
+                                            &lang,
+                                            "Saves everything captioned in the running session to a file of your choice",
+                                        ))
+                                        .clicked()
+                                    {
+                                        export_req.set(true);
+                                    }
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Retro Buffer:"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::Slider::new(&mut edit.retro_buffer_secs, 0..=300)
+                                                .suffix(" s"),
+                                        );
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.save_retro_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+R"),
+                                        );
+                                        if ui
+                                            .add_enabled(
+                                                edit.retro_buffer_secs > 0,
+                                                egui::Button::new(tr(&lang, "Save now")),
+                                            )
+                                            .clicked()
+                                        {
+                                            status.save_retro.store(true, Ordering::Relaxed);
+                                        }
                                     })
-                                    .show_ui(ui, |ui| {
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Keeps the last seconds of microphone audio, even outside a session. The key or button saves them as a WAV in the sessions folder. 0 = off",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Controls:"));
+                                    ui.horizontal(|ui| {
+                                        for (position, name) in [
+                                            (ControlsPosition::TopRight, tr(&lang, "Top right")),
+                                            (ControlsPosition::TopLeft, tr(&lang, "Top left")),
+                                            (ControlsPosition::BottomRight, tr(&lang, "Bottom right")),
+                                            (ControlsPosition::Hidden, tr(&lang, "Hidden")),
+                                        ] {
+                                            ui.selectable_value(&mut edit.controls_position, position, name);
+                                        }
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Settings Key:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.settings_hotkey)
+                                            .desired_width(120.0)
+                                            .hint_text("e.g. F2"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Close Key:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.close_hotkey)
+                                            .desired_width(120.0)
+                                            .hint_text("e.g. Ctrl+Q"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Learner Mode:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(
+                                            &mut edit.learner_mode,
+                                            tr(&lang, "Hide translation until revealed, key:"),
+                                        );
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.reveal_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Space"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "In Both mode the translation is obscured until you hover the caption or press the reveal key",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Numeric Phrases:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.numeric_phrase_mode,
+                                            NumericPhraseMode::Translate,
+                                            tr(&lang, "Translate"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.numeric_phrase_mode,
+                                            NumericPhraseMode::KeepVerbatim,
+                                            tr(&lang, "Keep verbatim"),
+                                        );
                                         ui.selectable_value(
-                                            edit_input_device,
-                                            String::new(),
-                                            "Default",
+                                            &mut edit.numeric_phrase_mode,
+                                            NumericPhraseMode::ShowSource,
+                                            tr(&lang, "Show source"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Numeric Ratio:"));
+                                    ui.add(egui::Slider::new(
+                                        &mut edit.numeric_ratio_threshold,
+                                        0.1..=1.0,
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label("");
+                                    ui.separator();
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Opacity:"));
+                                    let mut opacity_f32 = edit.opacity as f32;
+                                    if ui.add(egui::Slider::new(&mut opacity_f32, 0.0..=255.0)).changed() {
+                                        edit.opacity = opacity_f32 as u8;
+                                    }
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Text Color:"));
+                                    ui.color_edit_button_srgb(&mut edit.text_color);
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Confidence Colors:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.confidence_coloring, "");
+                                        ui.add_enabled_ui(edit.confidence_coloring, |ui| {
+                                            ui.add(
+                                                egui::Slider::new(&mut edit.confidence_high, 0.0..=1.0)
+                                                    .text(tr(&lang, "green from")),
+                                            );
+                                            ui.add(
+                                                egui::Slider::new(&mut edit.confidence_low, 0.0..=1.0)
+                                                    .text(tr(&lang, "yellow from")),
+                                            );
+                                        });
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Tints each caption by how confident the transcription was (green, yellow, red). Requests verbose_json; servers that don't return segment probabilities keep the normal text color",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Chroma Key:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.chroma_key_mode, "");
+                                        ui.add_enabled_ui(edit.chroma_key_mode, |ui| {
+                                            ui.color_edit_button_srgb(&mut edit.chroma_key_color);
+                                        });
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Fills the window with a solid key color instead of the translucent background, for keying out in OBS. Pick a text color that contrasts with it",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Reactive Opacity:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.reactive_opacity, tr(&lang, "Fade to"));
+                                        ui.add_enabled(
+                                            edit.reactive_opacity,
+                                            egui::Slider::new(&mut edit.idle_opacity, 0..=255),
+                                        );
+                                        ui.label(tr(&lang, "when silent"));
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Startup:"));
+                                    ui.checkbox(
+                                        &mut edit.hide_startup_frames,
+                                        tr(&lang, "Stay transparent for the first frames (avoids a black flash)"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Lower Third:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.lower_third, tr(&lang, "Bottom band"));
+                                        ui.add_enabled(
+                                            edit.lower_third,
+                                            egui::Slider::new(&mut edit.band_height, 60.0..=400.0)
+                                                .suffix(" px"),
                                         );
-                                        for name in input_devices {
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Draw the background and caption only in a fixed-height band along the bottom",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Audio Host:"));
+                                    let prev_host = edit.audio_host.clone();
+                                    egui::ComboBox::from_id_salt("audio_host_combo")
+                                        .selected_text(if edit.audio_host.is_empty() {
+                                            tr(&lang, "Default")
+                                        } else {
+                                            edit.audio_host.as_str()
+                                        })
+                                        .show_ui(ui, |ui| {
                                             ui.selectable_value(
-                                                edit_input_device,
-                                                name.clone(),
-                                                name.as_str(),
+                                                &mut edit.audio_host,
+                                                String::new(),
+                                                tr(&lang, "Default"),
                                             );
+                                            for name in audio_hosts {
+                                                ui.selectable_value(
+                                                    &mut edit.audio_host,
+                                                    name.clone(),
+                                                    name.as_str(),
+                                                );
+                                            }
+                                        });
+                                    if edit.audio_host != prev_host {
+                                        // Devices differ per host
+                                        *input_devices = list_input_devices(&edit.audio_host);
+                                        edit.input_device.clear();
+                                    }
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Input Device:"));
+                                    egui::ComboBox::from_id_salt("input_device_combo")
+                                        .selected_text(if edit.input_device.is_empty() {
+                                            tr(&lang, "Default")
+                                        } else {
+                                            edit.input_device.as_str()
+                                        })
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(
+                                                &mut edit.input_device,
+                                                String::new(),
+                                                tr(&lang, "Default"),
+                                            );
+                                            for name in input_devices.iter() {
+                                                ui.selectable_value(
+                                                    &mut edit.input_device,
+                                                    name.clone(),
+                                                    name.as_str(),
+                                                );
+                                            }
+                                        });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Sample Rate:"));
+                                    egui::ComboBox::from_id_salt("sample_rate_combo")
+                                        .selected_text(if edit.force_sample_rate == 0 {
+                                            tr(&lang, "Default").to_string()
+                                        } else {
+                                            format!("{} Hz", edit.force_sample_rate)
+                                        })
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(
+                                                &mut edit.force_sample_rate,
+                                                0,
+                                                tr(&lang, "Default"),
+                                            );
+                                            for rate in [16000, 22050, 32000, 44100, 48000] {
+                                                ui.selectable_value(
+                                                    &mut edit.force_sample_rate,
+                                                    rate,
+                                                    format!("{rate} Hz"),
+                                                );
+                                            }
+                                        })
+                                        .response
+                                        .on_hover_text(tr(
+                                            &lang,
+                                            "Falls back to the device default if unsupported. Takes effect after restart",
+                                        ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Detect Mute:"));
+                                    ui.checkbox(
+                                        &mut edit.detect_mute,
+                                        tr(&lang, "Treat silent (all-zero) input as muted"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Only When Visible:"));
+                                    ui.checkbox(
+                                        &mut edit.capture_only_when_visible,
+                                        tr(&lang, "Ignore speech while the window is minimized or hidden"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Clock:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.show_clock, tr(&lang, "Show"));
+                                        ui.selectable_value(
+                                            &mut edit.clock_mode,
+                                            ClockMode::WallClock,
+                                            tr(&lang, "Wall clock"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.clock_mode,
+                                            ClockMode::SessionElapsed,
+                                            tr(&lang, "Session elapsed"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Clock Format:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.clock_format)
+                                            .desired_width(400.0)
+                                            .hint_text("%H:%M:%S"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Clock Corner:"));
+                                    ui.horizontal(|ui| {
+                                        for (corner, name) in [
+                                            (Corner::TopLeft, tr(&lang, "Top left")),
+                                            (Corner::TopRight, tr(&lang, "Top right")),
+                                            (Corner::BottomLeft, tr(&lang, "Bottom left")),
+                                            (Corner::BottomRight, tr(&lang, "Bottom right")),
+                                        ] {
+                                            ui.selectable_value(&mut edit.clock_corner, corner, name);
                                         }
                                     });
-                                ui.end_row();
-                            });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Noise Filter:"));
+                                    ui.checkbox(
+                                        &mut edit.filter_nonlexical,
+                                        tr(&lang, "Discard results with no words (punctuation, symbols, emoji)"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Extra Noise Chars:"));
+                                    ui.add_enabled(
+                                        edit.filter_nonlexical,
+                                        egui::TextEdit::singleline(&mut edit.extra_noise_chars)
+                                            .desired_width(400.0)
+                                            .hint_text("e.g. ㅋㅎ"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Status Bar:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.show_status_bar, tr(&lang, "Show"));
+                                        ui.selectable_value(
+                                            &mut edit.status_bar_position,
+                                            BarPosition::Top,
+                                            tr(&lang, "Top"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.status_bar_position,
+                                            BarPosition::Bottom,
+                                            tr(&lang, "Bottom"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Processing Indicator:"));
+                                    ui.checkbox(
+                                        &mut edit.show_processing_indicator,
+                                        tr(&lang, "Show a spinner while a phrase is processed"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Upload Progress:"));
+                                    ui.checkbox(
+                                        &mut edit.show_upload_progress,
+                                        tr(&lang, "Show a progress bar while audio uploads"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Auto Start:"));
+                                    ui.checkbox(
+                                        &mut edit.auto_start_session,
+                                        tr(&lang, "Start a session on launch"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Startup Grace:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.startup_grace_secs, 0..=60)
+                                            .suffix(" s"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Speech in the first seconds of a session (mic handling, \"testing\") is not transcribed or logged. 0 = off",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Keep Sessions:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.max_session_files, 0..=1000)
+                                            .suffix(tr(&lang, " files")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Deletes the oldest session logs beyond this many, at startup and when a session starts. Only session_*.txt files are counted; a session's subtitle file is deleted with its last log. 0 = keep all",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Schedule:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.schedule_enabled, tr(&lang, "Run from"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.schedule_start)
+                                                .desired_width(50.0)
+                                                .hint_text("HH:MM"),
+                                        );
+                                        ui.label(tr(&lang, "to"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.schedule_end)
+                                                .desired_width(50.0)
+                                                .hint_text("HH:MM"),
+                                        );
+                                        if let Some(status) =
+                                            schedule_status(edit, chrono::Local::now().time())
+                                        {
+                                            ui.label(format!("({status})"));
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Starts the session when the window opens (also when launched inside it) and stops it when it closes. An end before the start crosses midnight",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Cut Overlap:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.overlap_carry_ms, 0..=1500)
+                                            .suffix(" ms"),
+                                    )
+                                    .on_hover_text(
+                                        tr(&lang, "Audio carried into the next phrase when a long phrase is cut"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Control API:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.control_api_enabled, tr(&lang, "Enable"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.control_api_bind)
+                                                .desired_width(200.0),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(&lang, "Takes effect after restart"));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Caption Pipe:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut edit.pipe_name)
+                                            .desired_width(200.0)
+                                            .hint_text(tr(&lang, "Off")),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Streams each caption as a JSON line to local consumers: a named pipe on Windows, a Unix socket in the temp folder elsewhere. Takes effect after restart",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Merge Fragments:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.merge_fragments, tr(&lang, "Within"));
+                                        ui.add_enabled(
+                                            edit.merge_fragments,
+                                            egui::Slider::new(&mut edit.merge_gap_ms, 100..=2000)
+                                                .suffix(" ms"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(
+                                        tr(&lang, "Re-transcribe a short phrase together with one that follows it closely"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Repeated Text:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.repeat_handling,
+                                            RepeatHandling::Keep,
+                                            tr(&lang, "Keep"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.repeat_handling,
+                                            RepeatHandling::Skip,
+                                            tr(&lang, "Skip"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.repeat_handling,
+                                            RepeatHandling::SuffixOnly,
+                                            tr(&lang, "New part only"),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Repeat Similarity:"));
+                                    ui.add_enabled(
+                                        edit.repeat_handling != RepeatHandling::Keep,
+                                        egui::Slider::new(&mut edit.repeat_similarity, 0.5..=1.0),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Debug Log:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.debug_logging, tr(&lang, "Write debug.log"));
+                                        ui.add_enabled(
+                                            edit.debug_logging,
+                                            egui::Checkbox::new(
+                                                &mut edit.debug_log_bodies,
+                                                tr(&lang, "Include bodies"),
+                                            ),
+                                        );
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Low Power:"));
+                                    ui.checkbox(
+                                        &mut edit.low_power,
+                                        tr(&lang, "Poll and repaint less often (adds latency)"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Click-through:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(
+                                            &mut edit.mouse_passthrough,
+                                            tr(&lang, "Let clicks pass to the window underneath"),
+                                        );
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.passthrough_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+T"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "The overlay's buttons and drag/resize stop working too. To turn it off, press the global click-through hotkey from any app, focus the overlay and press this key, or untick it here",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Global Hotkeys:"));
+                                    ui.horizontal(|ui| {
+                                        ui.label(tr(&lang, "Session"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.global_session_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+Alt+C"),
+                                        );
+                                        ui.label(tr(&lang, "Show/Hide"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.global_visibility_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+Alt+H"),
+                                        );
+                                        ui.label(tr(&lang, "Click-through"));
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut edit.global_passthrough_hotkey)
+                                                .desired_width(90.0)
+                                                .hint_text("e.g. Ctrl+Alt+P"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Work even while another app has focus. Leave empty to disable. A key already taken by another app is skipped",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "All Workspaces:"));
+                                    ui.checkbox(
+                                        &mut edit.all_workspaces,
+                                        tr(&lang, "Show on every virtual desktop"),
+                                    )
+                                    .on_hover_text(
+                                        tr(&lang, "Windows and Linux (X11) only. On Windows this also hides the overlay from the taskbar."),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Taskbar:"));
+                                    ui.checkbox(
+                                        &mut edit.skip_taskbar,
+                                        tr(&lang, "Hide the overlay from the taskbar"),
+                                    )
+                                    .on_hover_text(
+                                        tr(&lang, "Takes effect after restart. Windows; on Linux (X11) the overlay becomes a utility window, which most window managers leave out of the taskbar and Alt+Tab. Not supported on macOS or Wayland."),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Focus:"));
+                                    ui.checkbox(
+                                        &mut edit.no_focus,
+                                        tr(&lang, "Don't take focus when the overlay appears"),
+                                    )
+                                    .on_hover_text(
+                                        tr(&lang, "Takes effect after restart. Windows and macOS only. Hotkeys still need the overlay to be clicked first."),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Log Flush:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.log_flush_mode,
+                                            LogFlushMode::EveryLine,
+                                            tr(&lang, "Every line"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.log_flush_mode,
+                                            LogFlushMode::Periodic,
+                                            tr(&lang, "Every few seconds"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.log_flush_mode,
+                                            LogFlushMode::OnClose,
+                                            tr(&lang, "On session end"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Less frequent flushing helps on network drives, but a crash can lose the unflushed lines",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Subtitles:"));
+                                    ui.horizontal(|ui| {
+                                        ui.selectable_value(
+                                            &mut edit.subtitle_format,
+                                            SubtitleFormat::Off,
+                                            tr(&lang, "Off"),
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.subtitle_format,
+                                            SubtitleFormat::Srt,
+                                            "SRT",
+                                        );
+                                        ui.selectable_value(
+                                            &mut edit.subtitle_format,
+                                            SubtitleFormat::Vtt,
+                                            "WebVTT",
+                                        );
+                                        ui.separator();
+                                        ui.add_enabled_ui(
+                                            edit.subtitle_format != SubtitleFormat::Off,
+                                            |ui| {
+                                                ui.selectable_value(
+                                                    &mut edit.subtitle_content,
+                                                    SubtitleContent::Source,
+                                                    tr(&lang, "Original"),
+                                                );
+                                                ui.selectable_value(
+                                                    &mut edit.subtitle_content,
+                                                    SubtitleContent::Translation,
+                                                    tr(&lang, "Translation"),
+                                                );
+                                                ui.selectable_value(
+                                                    &mut edit.subtitle_content,
+                                                    SubtitleContent::Both,
+                                                    tr(&lang, "Both"),
+                                                );
+                                            },
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Also writes each session as a subtitle file next to its log, one cue per phrase timed from the session start, for captioning a recording afterward. Applies from the next session",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Subtitle Offset:"));
+                                    ui.add_enabled(
+                                        edit.subtitle_format != SubtitleFormat::Off,
+                                        egui::Slider::new(&mut edit.subtitle_offset_ms, -10000..=10000)
+                                            .suffix(" ms"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Shifts every cue in the subtitle file, e.g. when the recording started before or after the session. Negative values make cues earlier",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Split Log:"));
+                                    ui.add(
+                                        egui::Slider::new(&mut edit.session_rotate_mins, 0..=240)
+                                            .suffix(" min"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Continue long sessions in session_<time>_partN.txt files. 0 keeps one file",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Merge Sentences:"));
+                                    ui.checkbox(
+                                        &mut edit.smart_merge_sentences,
+                                        tr(&lang, "Join log entries that continue the same sentence"),
+                                    )
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "When a phrase has no end punctuation and the next starts lowercase, they are written as one log entry. Captions are still shown per phrase; each entry is written once the next phrase arrives",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Turn Markers:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.mark_turns, tr(&lang, "Mark gaps over"));
+                                        ui.add_enabled(
+                                            edit.mark_turns,
+                                            egui::Slider::new(&mut edit.turn_gap_secs, 1.0..=60.0)
+                                                .suffix(" s"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(
+                                        tr(&lang, "Writes a separator to the session log before a phrase that follows a long pause"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Caption Queue:"));
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut edit.queue_captions, tr(&lang, "Show each for"));
+                                        ui.add_enabled(
+                                            edit.queue_captions,
+                                            egui::Slider::new(&mut edit.min_display_ms, 500..=10000)
+                                                .suffix(" ms"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(
+                                        tr(&lang, "Holds captions that finish while the previous one is still on screen instead of replacing it"),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Max Queued:"));
+                                    ui.add_enabled(
+                                        edit.queue_captions,
+                                        egui::Slider::new(&mut edit.max_queued_captions, 1..=10),
+                                    )
+                                    .on_hover_text(tr(&lang, "Oldest pending captions are dropped beyond this"));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Rate Limits:"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::Slider::new(&mut edit.max_backoff_secs, 1..=120)
+                                                .text(tr(&lang, "max wait"))
+                                                .suffix(" s"),
+                                        );
+                                        ui.checkbox(
+                                            &mut edit.rate_limit_show_source,
+                                            tr(&lang, "Show source instead of waiting"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "On HTTP 429 the request is retried after the server's Retry-After delay, up to the max wait",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "Connection Pool:"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::Slider::new(&mut edit.pool_max_idle_per_host, 0..=8)
+                                                .text(tr(&lang, "idle")),
+                                        );
+                                        ui.add(
+                                            egui::Slider::new(&mut edit.pool_idle_timeout_secs, 5..=300)
+                                                .suffix(" s"),
+                                        );
+                                    })
+                                    .response
+                                    .on_hover_text(tr(
+                                        &lang,
+                                        "Idle connections kept open per API host, and for how long. 0 opens a new connection for every request.",
+                                    ));
+                                    ui.end_row();
+
+                                    ui.label(tr(&lang, "HTTP/2:"));
+                                    ui.checkbox(&mut edit.http2, tr(&lang, "Use HTTP/2 when the server supports it"));
+                                    ui.end_row();
+                                });
+                        });
                     });
                 },
             );
@@ -416,82 +2223,464 @@ impl eframe::App for App {
             if close_req.get() {
                 self.show_settings = false;
             }
+            if export_req.get() {
+                self.export_session();
+            }
+
+            // Flag Both mode without a target once when it first appears, so a
+            // missing translation isn't mistaken for a broken API
+            let missing_target = self.edit.missing_translation_target();
+            if missing_target && !self.warned_missing_target {
+                let lang = &self.edit.ui_language;
+                self.toast = Some((
+                    tr(lang, "No target language: showing source only").to_string(),
+                    Instant::now(),
+                ));
+            }
+            self.warned_missing_target = missing_target;
 
             // Write back to shared settings and save to file
-            let mut s = self.settings.lock().unwrap();
-            s.api_url = self.edit_api_url.clone();
-            s.api_key = self.edit_api_key.clone();
-            s.silence_threshold = self.edit_threshold;
-            s.language = self.edit_language.clone();
-            s.font_size = self.edit_font_size;
-            s.chat_api_url = self.edit_chat_api_url.clone();
-            s.chat_api_key = self.edit_chat_api_key.clone();
-            s.chat_model = self.edit_chat_model.clone();
-            s.target_language = self.edit_target_language.clone();
-            s.display_mode = self.edit_display_mode.clone();
-            s.opacity = self.edit_opacity;
-            s.input_device = self.edit_input_device.clone();
+            let mut s = self.settings.lock().unwrap_or_else(recover_poison);
+            *s = self.edit.clone();
             s.save();
         }
 
-        let font_size = self.edit_font_size;
+        let font_size = self.edit.font_size;
+
+        // Reactive opacity: ease between the idle level and the configured
+        // opacity (the upper bound) as speech starts and stops
+        let speaking = self.status.speaking.load(Ordering::Relaxed);
+        let target = if speaking { 1.0 } else { 0.0 };
+        self.activity += (target - self.activity) * OPACITY_EASING;
+        if (target - self.activity).abs() > 0.01 {
+            ctx.request_repaint_after(Duration::from_millis(16));
+        } else {
+            self.activity = target;
+        }
+        let opacity = if self.edit.reactive_opacity {
+            let idle = self.edit.idle_opacity.min(self.edit.opacity) as f32;
+            (idle + (self.edit.opacity as f32 - idle) * self.activity) as u8
+        } else {
+            self.edit.opacity
+        };
 
-        let panel_frame = egui::Frame::new()
-            .fill(egui::Color32::from_black_alpha(self.edit_opacity))
-            .inner_margin(20.0);
+        // Chroma key: a solid, fully opaque key color across the whole window
+        // so OBS can key it out cleanly instead of compositing transparency
+        let background = if self.edit.chroma_key_mode {
+            let [r, g, b] = self.edit.chroma_key_color;
+            egui::Color32::from_rgb(r, g, b)
+        } else {
+            egui::Color32::from_black_alpha(opacity)
+        };
+        let panel_fill = if self.edit.lower_third && !self.edit.chroma_key_mode {
+            egui::Color32::TRANSPARENT
+        } else {
+            background
+        };
+        let panel_frame = egui::Frame::new().fill(panel_fill).inner_margin(20.0);
 
         egui::CentralPanel::default()
             .frame(panel_frame)
             .show(ctx, |ui| {
                 // Centered transcript text with auto-shrink
-                let text = self.transcript.lock().unwrap().clone();
-                let display = if text.is_empty() { "..." } else { &text };
+                let text = self.transcript.lock().unwrap_or_else(recover_poison).clone();
+                let decorated = decorate_caption(&text, &self.edit);
+                // Idle placeholder, optionally cycling "." / ".." / "..." to show
+                // the app is listening. Low power mode caps the step rate at its
+                // repaint interval.
+                let placeholder = if self.edit.animate_placeholder {
+                    let min_step_ms =
+                        if self.edit.low_power { self.edit.repaint_interval_ms() } else { 1 };
+                    let step_ms = (self.edit.placeholder_step_ms as u64).max(min_step_ms);
+                    if text.is_empty() {
+                        ctx.request_repaint_after(Duration::from_millis(step_ms));
+                    }
+                    let step = (ctx.input(|i| i.time) * 1000.0) as u64 / step_ms;
+                    ".".repeat(step as usize % 3 + 1)
+                } else {
+                    "...".to_string()
+                };
+                let display = if text.is_empty() { &placeholder } else { &decorated };
                 let panel_rect = ui.max_rect();
 
+                // Emphasized phrase: larger and in the accent color until the next one
+                let emphasized =
+                    !text.is_empty() && self.emphasized_caption.as_deref() == Some(text.as_str());
+                // Confidence coloring: green / yellow / red by the phrase's
+                // transcription confidence, when the server reports it
+                let confidence = (self.edit.confidence_coloring && !text.is_empty())
+                    .then(|| *self.status.caption_confidence.lock().unwrap_or_else(recover_poison))
+                    .flatten();
+                let (font_size, text_color) = if emphasized {
+                    let [r, g, b] = self.edit.emphasis_color;
+                    (font_size * self.edit.emphasis_scale, egui::Color32::from_rgb(r, g, b))
+                } else if let Some(confidence) = confidence {
+                    let color = if confidence >= self.edit.confidence_high {
+                        egui::Color32::from_rgb(130, 230, 130)
+                    } else if confidence >= self.edit.confidence_low {
+                        egui::Color32::from_rgb(240, 210, 80)
+                    } else {
+                        egui::Color32::from_rgb(240, 110, 100)
+                    };
+                    (font_size, color)
+                } else {
+                    let [r, g, b] = self.edit.text_color;
+                    (font_size, egui::Color32::from_rgb(r, g, b))
+                };
+
+                // Lower third: a fixed-height full-width band at the bottom holds
+                // the background and caption; the rest of the window stays clear
+                let text_rect = if self.edit.lower_third {
+                    let screen = ctx.screen_rect();
+                    let band = egui::Rect::from_min_max(
+                        egui::pos2(
+                            screen.left(),
+                            (screen.bottom() - self.edit.band_height).max(screen.top()),
+                        ),
+                        screen.right_bottom(),
+                    );
+                    ctx.layer_painter(ui.layer_id()).rect_filled(band, 0.0, background);
+                    band.shrink2(egui::vec2(20.0, 0.0))
+                } else {
+                    panel_rect
+                };
+
+                // Optional status strip; the caption is laid out in what remains
+                let mut caption_rect = text_rect;
+                if self.edit.show_status_bar {
+                    let bar_rect = match self.edit.status_bar_position {
+                        BarPosition::Top => {
+                            caption_rect.min.y += STATUS_BAR_HEIGHT;
+                            egui::Rect::from_min_size(
+                                text_rect.left_top(),
+                                egui::vec2(text_rect.width(), STATUS_BAR_HEIGHT),
+                            )
+                        }
+                        BarPosition::Bottom => {
+                            caption_rect.max.y -= STATUS_BAR_HEIGHT;
+                            egui::Rect::from_min_max(
+                                egui::pos2(text_rect.left(), caption_rect.bottom()),
+                                text_rect.right_bottom(),
+                            )
+                        }
+                    };
+                    let target = if self.edit.target_language.is_empty() {
+                        "—"
+                    } else {
+                        language_name(TARGET_LANGUAGES, &self.edit.target_language)
+                    };
+                    let lang = &self.edit.ui_language;
+                    let mode = tr(lang, self.edit.display_mode.label());
+                    let session = if self.session_active.load(Ordering::Relaxed) {
+                        format!("● {}", tr(lang, "Recording"))
+                    } else {
+                        format!("○ {}", tr(lang, "Idle"))
+                    };
+                    let mut source =
+                        language_name(SOURCE_LANGUAGES, &self.edit.language).to_string();
+                    if self.edit.forced_language().is_none()
+                        && let Some(detected) =
+                            &*self.status.detected_language.lock().unwrap_or_else(recover_poison)
+                    {
+                        source.push_str(&format!(" ({detected})"));
+                    }
+                    let mut bar_text = format!(
+                        "{} → {}   |   {}   |   {}",
+                        source,
+                        target,
+                        mode,
+                        session
+                    );
+                    if let Some(schedule) =
+                        schedule_status(&self.edit, chrono::Local::now().time())
+                    {
+                        bar_text.push_str(&format!("   |   ⏱ {schedule}"));
+                    }
+                    ui.painter().text(
+                        bar_rect.left_center(),
+                        egui::Align2::LEFT_CENTER,
+                        bar_text,
+                        egui::FontId::proportional(14.0),
+                        egui::Color32::from_white_alpha(180),
+                    );
+                }
+
                 // Find the largest font size that fits
-                let available = panel_rect.shrink(20.0); // account for inner margin
-                let min_size = 12.0_f32;
+                let available = caption_rect.shrink(20.0); // account for inner margin
+                let min_size = self.edit.min_font_size.min(font_size);
                 let mut size = font_size;
-                while size > min_size {
-                    let galley = ui.fonts(|f| {
-                        f.layout(
-                            display.to_string(),
-                            egui::FontId::proportional(size),
-                            egui::Color32::WHITE,
-                            available.width(),
-                        )
-                    });
+                let mut fits = false;
+                loop {
+                    let mut job = egui::text::LayoutJob::simple(
+                        display.to_string(),
+                        egui::FontId::proportional(size),
+                        egui::Color32::WHITE,
+                        available.width(),
+                    );
+                    job.wrap.break_anywhere = self.edit.break_long_words;
+                    let galley = ui.fonts(|f| f.layout_job(job));
                     if galley.size().y <= available.height() {
+                        fits = true;
+                        break;
+                    }
+                    if size <= min_size {
                         break;
                     }
                     size = (size - 2.0).max(min_size);
                 }
 
-                ui.allocate_new_ui(egui::UiBuilder::new().max_rect(panel_rect), |ui| {
-                    ui.with_layout(
-                        egui::Layout::centered_and_justified(egui::Direction::TopDown),
-                        |ui| {
-                            let response = ui.add(
-                                egui::Label::new(
-                                    egui::RichText::new(display)
-                                        .color(egui::Color32::WHITE)
-                                        .size(size),
-                                )
-                                .selectable(false)
-                                .sense(egui::Sense::drag()),
-                            );
-                            if response.drag_started() {
-                                ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
-                            }
-                        },
+                // Ease toward the fitted size instead of snapping between phrases
+                if self.edit.smooth_font_size {
+                    let target = size;
+                    self.shown_font_size +=
+                        (target - self.shown_font_size) * self.edit.font_smoothing;
+                    if (target - self.shown_font_size).abs() > 0.5 {
+                        ctx.request_repaint_after(Duration::from_millis(16));
+                    } else {
+                        self.shown_font_size = target;
+                    }
+                    size = self.shown_font_size;
+                } else {
+                    self.shown_font_size = size;
+                }
+
+                // Learner mode: in Both mode the translation (the caption's last
+                // line) stays obscured until hovered, or revealed with the reveal
+                // key for the rest of that phrase
+                let hovered = ctx
+                    .input(|i| i.pointer.hover_pos())
+                    .is_some_and(|p| caption_rect.contains(p));
+                let hide_translation = self.edit.learner_mode
+                    && self.edit.display_mode == DisplayMode::Both
+                    && self.revealed_caption.as_deref() != Some(text.as_str())
+                    && !hovered;
+                let font_id = egui::FontId::proportional(size);
+                let mut job = egui::text::LayoutJob::default();
+                match display.rsplit_once('\n').filter(|_| hide_translation) {
+                    Some((source, translation)) => {
+                        let hidden = egui::Color32::from_gray(90);
+                        job.append(
+                            &format!("{source}\n"),
+                            0.0,
+                            egui::TextFormat::simple(font_id.clone(), text_color),
+                        );
+                        job.append(
+                            translation,
+                            0.0,
+                            egui::TextFormat {
+                                font_id,
+                                color: hidden,
+                                background: hidden,
+                                ..Default::default()
+                            },
+                        );
+                    }
+                    None => job.append(
+                        display,
+                        0.0,
+                        egui::TextFormat::simple(font_id, text_color),
+                    ),
+                }
+                // Off: wrap at word boundaries and let an over-long token overflow
+                // (shrinking the font). On: any character may end a line.
+                job.wrap.break_anywhere = self.edit.break_long_words;
+                let caption: egui::WidgetText = job.into();
+                if !fits && self.edit.scroll_on_overflow {
+                    // Too long even at the minimum size: keep the newest text in view
+                    ui.allocate_new_ui(egui::UiBuilder::new().max_rect(caption_rect), |ui| {
+                        egui::ScrollArea::vertical()
+                            .stick_to_bottom(true)
+                            .auto_shrink(false)
+                            .show(ui, |ui| {
+                                ui.vertical_centered(|ui| {
+                                    ui.add(egui::Label::new(caption).selectable(false));
+                                });
+                            });
+                    });
+                } else {
+                    ui.allocate_new_ui(egui::UiBuilder::new().max_rect(caption_rect), |ui| {
+                        ui.with_layout(
+                            egui::Layout::centered_and_justified(egui::Direction::TopDown),
+                            |ui| {
+                                let response = ui.add(
+                                    egui::Label::new(caption)
+                                        .selectable(false)
+                                        .sense(egui::Sense::drag()),
+                                );
+                                if response.drag_started() && !self.edit.mouse_passthrough {
+                                    ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                                }
+                            },
+                        );
+                    });
+                }
+
+                // Spinner under the caption while a phrase is being processed
+                if self.edit.show_processing_indicator
+                    && self.status.processing.load(Ordering::Relaxed)
+                {
+                    let spinner_rect = egui::Rect::from_center_size(
+                        egui::pos2(caption_rect.center().x, caption_rect.bottom() - 12.0),
+                        egui::vec2(20.0, 20.0),
                     );
-                });
+                    ui.put(
+                        spinner_rect,
+                        egui::Spinner::new()
+                            .size(16.0)
+                            .color(egui::Color32::from_white_alpha(160)),
+                    );
+                }
+
+                // Thin upload progress bar along the bottom of the caption area
+                let upload_total = self.status.upload_total.load(Ordering::Relaxed);
+                let upload_sent = self.status.upload_sent.load(Ordering::Relaxed);
+                if self.edit.show_upload_progress
+                    && self.status.processing.load(Ordering::Relaxed)
+                    && upload_total > 0
+                    && upload_sent < upload_total
+                {
+                    let fraction = upload_sent as f32 / upload_total as f32;
+                    let track = egui::Rect::from_min_size(
+                        egui::pos2(caption_rect.left(), caption_rect.bottom() - 3.0),
+                        egui::vec2(caption_rect.width(), 3.0),
+                    );
+                    let mut filled = track;
+                    filled.set_width(track.width() * fraction);
+                    ui.painter().rect_filled(track, 0.0, egui::Color32::from_white_alpha(40));
+                    ui.painter().rect_filled(filled, 0.0, egui::Color32::from_white_alpha(160));
+                    ctx.request_repaint_after(Duration::from_millis(50));
+                }
+
+                // Optional clock in the chosen corner
+                if self.edit.show_clock {
+                    let clock_text = match self.edit.clock_mode {
+                        ClockMode::WallClock => {
+                            format_clock(chrono::Local::now().naive_local(), &self.edit.clock_format)
+                        }
+                        ClockMode::SessionElapsed => {
+                            let secs = self
+                                .session_started
+                                .map(|t| t.elapsed().as_secs() as i64)
+                                .unwrap_or(0);
+                            let elapsed = chrono::NaiveDateTime::default()
+                                + chrono::TimeDelta::seconds(secs);
+                            format_clock(elapsed, &self.edit.clock_format)
+                        }
+                    };
+                    let (anchor, pos) = match self.edit.clock_corner {
+                        Corner::TopLeft => (egui::Align2::LEFT_TOP, panel_rect.left_top()),
+                        Corner::TopRight => (egui::Align2::RIGHT_TOP, panel_rect.right_top()),
+                        Corner::BottomLeft => {
+                            (egui::Align2::LEFT_BOTTOM, panel_rect.left_bottom())
+                        }
+                        Corner::BottomRight => {
+                            (egui::Align2::RIGHT_BOTTOM, panel_rect.right_bottom())
+                        }
+                    };
+                    ui.painter().text(
+                        pos,
+                        anchor,
+                        clock_text,
+                        egui::FontId::proportional(16.0),
+                        egui::Color32::WHITE,
+                    );
+                }
+
+                // Rate-limit banner with the remaining backoff
+                let rate_limited_for = self
+                    .status
+                    .rate_limited_until
+                    .lock()
+                    .unwrap_or_else(recover_poison)
+                    .and_then(|until| until.checked_duration_since(Instant::now()));
+                if let Some(remaining) = rate_limited_for {
+                    ui.painter().text(
+                        egui::pos2(caption_rect.center().x, caption_rect.top()),
+                        egui::Align2::CENTER_TOP,
+                        format!(
+                            "{} {}s",
+                            tr(&self.edit.ui_language, "Rate limited, retrying in"),
+                            remaining.as_secs() + 1
+                        ),
+                        egui::FontId::proportional(14.0),
+                        egui::Color32::from_rgb(255, 200, 80),
+                    );
+                    ctx.request_repaint_after(Duration::from_millis(250));
+                }
+
+                // Last failed API call, until the same kind of request succeeds
+                let api_error = *self.status.api_error.lock().unwrap_or_else(recover_poison);
+                if let Some(error) = api_error {
+                    ui.painter().text(
+                        egui::pos2(caption_rect.center().x, caption_rect.top() + 18.0),
+                        egui::Align2::CENTER_TOP,
+                        api_error_text(&self.edit.ui_language, error),
+                        egui::FontId::proportional(14.0),
+                        egui::Color32::from_rgb(255, 110, 110),
+                    );
+                }
+
+                if self.status.warming_up.load(Ordering::Relaxed) {
+                    ui.painter().text(
+                        egui::pos2(caption_rect.center().x, caption_rect.top()),
+                        egui::Align2::CENTER_TOP,
+                        tr(&self.edit.ui_language, "Warming up…"),
+                        egui::FontId::proportional(14.0),
+                        egui::Color32::from_white_alpha(180),
+                    );
+                }
+
+                // Brief notice after a hotkey changes something
+                if let Some((text, shown)) = &self.toast {
+                    let age = shown.elapsed().as_secs_f32();
+                    if age < TOAST_SECS {
+                        let galley = ui.fonts(|f| {
+                            f.layout_no_wrap(
+                                text.clone(),
+                                egui::FontId::proportional(18.0),
+                                egui::Color32::WHITE,
+                            )
+                        });
+                        let toast_rect = egui::Rect::from_center_size(
+                            egui::pos2(caption_rect.center().x, caption_rect.top() + 40.0),
+                            galley.size(),
+                        );
+                        ui.painter().rect_filled(
+                            toast_rect.expand(8.0),
+                            6.0,
+                            egui::Color32::from_black_alpha(200),
+                        );
+                        ui.painter().galley(toast_rect.min, galley, egui::Color32::WHITE);
+                        ctx.request_repaint_after(Duration::from_millis(100));
+                    } else {
+                        self.toast = None;
+                    }
+                }
+
+                // Controls sit inside the panel margin, clear of the resize border
+                let controls = self.edit.controls_position;
+                let visible = controls != ControlsPosition::Hidden;
+
+                // Muted indicator next to the session button
+                if self.status.muted.load(Ordering::Relaxed) {
+                    let muted_rect = control_rect(panel_rect, controls, 128.0, 64.0);
+                    ui.put(
+                        muted_rect,
+                        egui::Label::new(
+                            egui::RichText::new("MUTED")
+                                .color(egui::Color32::from_rgb(255, 200, 80))
+                                .size(12.0),
+                        )
+                        .selectable(false),
+                    );
+                }
+
+                if !visible {
+                    return;
+                }
 
                 // Session start/stop button
-                let session_rect = egui::Rect::from_min_size(
-                    egui::pos2(panel_rect.right() - 96.0, panel_rect.top()),
-                    egui::vec2(32.0, 32.0),
-                );
+                let session_rect = control_rect(panel_rect, controls, 64.0, 32.0);
                 let is_active = self.session_active.load(Ordering::Relaxed);
                 let session_btn = ui.put(
                     session_rect,
@@ -507,18 +2696,26 @@ impl eframe::App for App {
                     .frame(false),
                 );
                 if session_btn.clicked() {
-                    let new_state = !is_active;
-                    self.session_active.store(new_state, Ordering::Relaxed);
-                    if !new_state {
-                        *self.transcript.lock().unwrap() = String::new();
-                    }
+                    self.toggle_session();
                 }
 
-                // Settings button overlaid to the left of close button
-                let settings_rect = egui::Rect::from_min_size(
-                    egui::pos2(panel_rect.right() - 64.0, panel_rect.top()),
-                    egui::vec2(32.0, 32.0),
+                // Copy the caption on screen, e.g. to paste a quote into chat
+                let copy_rect = control_rect(panel_rect, controls, 96.0, 32.0);
+                let copy_btn = ui.put(
+                    copy_rect,
+                    egui::Button::new(egui::RichText::new("📋").size(16.0)).frame(false),
                 );
+                if copy_btn.on_hover_text(tr(&self.edit.ui_language, "Copy caption")).clicked() {
+                    let text = self.transcript.lock().unwrap_or_else(recover_poison).clone();
+                    if !text.is_empty() {
+                        ctx.copy_text(text);
+                        let toast = tr(&self.edit.ui_language, "Caption copied").to_string();
+                        self.toast = Some((toast, Instant::now()));
+                    }
+                }
+
+                // Settings button next to the close button
+                let settings_rect = control_rect(panel_rect, controls, 32.0, 32.0);
                 let settings_btn = ui.put(
                     settings_rect,
                     egui::ImageButton::new(
@@ -528,31 +2725,11 @@ impl eframe::App for App {
                     .frame(false),
                 );
                 if settings_btn.clicked() {
-                    self.show_settings = !self.show_settings;
-                    if self.show_settings {
-                        let s = self.settings.lock().unwrap();
-                        self.edit_api_url = s.api_url.clone();
-                        self.edit_api_key = s.api_key.clone();
-                        self.edit_threshold = s.silence_threshold;
-                        self.edit_language = s.language.clone();
-                        self.edit_font_size = s.font_size;
-                        self.edit_chat_api_url = s.chat_api_url.clone();
-                        self.edit_chat_api_key = s.chat_api_key.clone();
-                        self.edit_chat_model = s.chat_model.clone();
-                        self.edit_target_language = s.target_language.clone();
-                        self.edit_display_mode = s.display_mode.clone();
-                        self.edit_opacity = s.opacity;
-                        self.edit_input_device = s.input_device.clone();
-                        drop(s);
-                        self.input_devices = list_input_devices();
-                    }
+                    self.toggle_settings();
                 }
 
-                // Close button overlaid at top-right
-                let btn_rect = egui::Rect::from_min_size(
-                    egui::pos2(panel_rect.right() - 32.0, panel_rect.top()),
-                    egui::vec2(32.0, 32.0),
-                );
+                // Close button in the chosen corner
+                let btn_rect = control_rect(panel_rect, controls, 0.0, 32.0);
                 let btn = ui.put(
                     btn_rect,
                     egui::ImageButton::new(
@@ -566,6 +2743,6 @@ impl eframe::App for App {
                 }
             });
 
-        ctx.request_repaint_after(Duration::from_millis(200));
+        ctx.request_repaint_after(Duration::from_millis(self.edit.repaint_interval_ms()));
     }
 }
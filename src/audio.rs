@@ -1,13 +1,166 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::collections::VecDeque;
+use std::io::Read;
+use std::path::PathBuf;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc, Mutex,
+    atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+    Arc, Mutex, OnceLock, PoisonError,
 };
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::settings::{DisplayMode, Settings, MAX_PHRASE_SECS, SILENCE_CHUNKS_TO_END};
+use crate::settings::{
+    language_name, DisplayMode, LogFlushMode, NumericPhraseMode, RepeatHandling, ResampleQuality,
+    Settings, SubtitleContent, SubtitleFormat, UploadMode, DEFAULT_TRANSLATION_PROMPT, LOG_FLUSH_SECS,
+    MERGE_FRAGMENT_SECS, MUTE_DETECT_SECS, POLL_INTERVAL_MS, SOURCE_LANGUAGES,
+};
+use crate::text::{
+    apply_canonical_terms, apply_casing, ends_sentence, glossary_prompt, glossary_terms,
+    is_blank_text, novel_suffix, numeric_ratio, overlap_ratio, strip_overlap, strip_quotes,
+    term_pair_prompt,
+};
+
+// Resolves the configured cpal host by name, falling back to the default host
+// when it's empty or not available on this platform
+pub fn select_host(name: &str) -> cpal::Host {
+    if name.is_empty() {
+        return cpal::default_host();
+    }
+    cpal::available_hosts()
+        .into_iter()
+        .find(|id| id.name() == name)
+        .and_then(|id| cpal::host_from_id(id).ok())
+        .unwrap_or_else(|| {
+            eprintln!("Audio host '{name}' not available, using default");
+            cpal::default_host()
+        })
+}
+
+pub fn list_audio_hosts() -> Vec<String> {
+    cpal::available_hosts()
+        .into_iter()
+        .map(|id| id.name().to_string())
+        .collect()
+}
+
+// Rate of the uploaded clip. Whisper works at 16 kHz internally, so sending the
+// device rate (often 44.1/48 kHz) only costs bandwidth and latency.
+const TRANSCRIBE_SAMPLE_RATE: u32 = 16_000;
+
+// Resamples mono audio to TRANSCRIBE_SAMPLE_RATE. With Linear, downsampling
+// averages the input under each output sample (a box low-pass, so speech
+// doesn't alias) and upsampling interpolates linearly; Sinc uses a windowed
+// sinc low-pass instead. Non-integer ratios like 44.1k -> 16k work with both,
+// and the whole phrase is resampled at once so there are no seams inside it.
+fn resample_to_16k(samples: &[f32], src_rate: u32, quality: ResampleQuality) -> Vec<f32> {
+    if src_rate == TRANSCRIBE_SAMPLE_RATE || samples.is_empty() {
+        return samples.to_vec();
+    }
+    if quality == ResampleQuality::Sinc {
+        return resample_sinc(samples, src_rate);
+    }
+    let len = samples.len();
+    let ratio = src_rate as f64 / TRANSCRIBE_SAMPLE_RATE as f64;
+    let out_len = ((len as f64 / ratio).round() as usize).max(1);
+    (0..out_len)
+        .map(|i| {
+            if ratio > 1.0 {
+                let start = i as f64 * ratio;
+                let end = ((i + 1) as f64 * ratio).min(len as f64);
+                let (mut sum, mut weight) = (0.0f64, 0.0f64);
+                for (k, &s) in samples
+                    .iter()
+                    .enumerate()
+                    .take((end.ceil() as usize).min(len))
+                    .skip(start.floor() as usize)
+                {
+                    let overlap = (end.min(k as f64 + 1.0) - start.max(k as f64)).max(0.0);
+                    sum += s as f64 * overlap;
+                    weight += overlap;
+                }
+                if weight > 0.0 { (sum / weight) as f32 } else { 0.0 }
+            } else {
+                let pos = i as f64 * ratio;
+                let k = (pos.floor() as usize).min(len - 1);
+                let next = samples[(k + 1).min(len - 1)];
+                let frac = (pos - k as f64) as f32;
+                samples[k] + (next - samples[k]) * frac
+            }
+        })
+        .collect()
+}
+
+const SINC_ZERO_CROSSINGS: usize = 16; // kernel half-width, in cutoff periods
+const MAX_SINC_PHASES: usize = 1024; // odd rates round positions to 1/1024 sample
+
+// Windowed-sinc resampling: each output sample is the input convolved with a
+// Blackman-windowed sinc centred on its position. The cutoff is the lower of
+// the two Nyquist rates, so downsampling filters out what would alias.
+// Output positions repeat their fractional offset every few samples (one
+// phase at 48 kHz, 160 at 44.1 kHz), so the ~100 taps are computed once per
+// phase instead of per output sample. A 30 s phrase takes about 40 ms in a
+// release build at either rate (was 1.2 s), around 4x the Linear cost.
+fn resample_sinc(samples: &[f32], src_rate: u32) -> Vec<f32> {
+    let len = samples.len();
+    let ratio = src_rate as f64 / TRANSCRIBE_SAMPLE_RATE as f64;
+    let out_len = ((len as f64 / ratio).round() as usize).max(1);
+    let cutoff = (1.0 / ratio).min(1.0); // fraction of the input Nyquist rate
+    let half_width = SINC_ZERO_CROSSINGS as f64 / cutoff;
+
+    let mut divisor = (src_rate, TRANSCRIBE_SAMPLE_RATE);
+    while divisor.1 != 0 {
+        divisor = (divisor.1, divisor.0 % divisor.1);
+    }
+    let phases = ((TRANSCRIBE_SAMPLE_RATE / divisor.0) as usize).min(MAX_SINC_PHASES);
+    // kernel[j] weights the input sample `j - reach` away from the floor of
+    // the output position
+    let reach = half_width.ceil() as usize;
+    let width = 2 * reach + 1;
+    let kernels: Vec<Vec<f32>> = (0..phases)
+        .map(|phase| {
+            let frac = phase as f64 / phases as f64;
+            (0..width)
+                .map(|j| {
+                    let t = j as f64 - reach as f64 - frac;
+                    if t.abs() >= half_width {
+                        return 0.0;
+                    }
+                    let x = std::f64::consts::PI * cutoff * t;
+                    let sinc = if x.abs() < 1e-9 { 1.0 } else { x.sin() / x };
+                    let w = 2.0 * std::f64::consts::PI * (t / (2.0 * half_width) + 0.5);
+                    let window = 0.42 - 0.5 * w.cos() + 0.08 * (2.0 * w).cos();
+                    (sinc * window) as f32
+                })
+                .collect()
+        })
+        .collect();
+
+    (0..out_len)
+        .map(|i| {
+            let pos = i as f64 * ratio;
+            let mut base = pos.floor() as usize;
+            let mut phase = ((pos - base as f64) * phases as f64).round() as usize;
+            if phase == phases {
+                base += 1;
+                phase = 0;
+            }
+            let first = base as isize - reach as isize;
+            let skip = (-first).max(0) as usize;
+            let end = (len as isize - first).clamp(0, width as isize) as usize;
+            let (mut sum, mut weight) = (0.0f32, 0.0f32);
+            if skip < end {
+                let input = &samples[(first + skip as isize) as usize..];
+                for (&tap, &s) in kernels[phase][skip..end].iter().zip(input) {
+                    sum += s * tap;
+                    weight += tap;
+                }
+            }
+            // Normalizing by the tap sum keeps the gain at 1, including near
+            // the edges where the kernel is cut off
+            if weight.abs() > 1e-9 { sum / weight } else { 0.0 }
+        })
+        .collect()
+}
 
 fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
     let num_samples = samples.len();
@@ -36,6 +189,223 @@ fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
     buf
 }
 
+// Worker state surfaced in the overlay
+#[derive(Default)]
+pub struct WorkerStatus {
+    pub muted: AtomicBool,
+    pub speaking: AtomicBool,   // the VAD is inside a phrase
+    pub processing: AtomicBool, // a transcription/translation request is in flight
+    pub upload_sent: AtomicU64,
+    pub upload_total: AtomicU64,
+    pub captions: Mutex<CaptionQueue>,
+    pub rate_limited_until: Mutex<Option<Instant>>, // shown as a banner while set
+    pub save_retro: AtomicBool, // set by the UI, the VAD thread writes the buffer out
+    pub warming_up: AtomicBool, // inside startup_grace_secs after a session start
+    pub caption_confidence: Mutex<Option<f32>>, // of the caption on screen, 0..1
+    pub window_hidden: AtomicBool, // the overlay is minimized, set by the UI each frame
+    pub api_error: Mutex<Option<ApiError>>, // last failed request, shown as a banner
+    pub stopping: AtomicBool, // set on exit so retry waits end early
+    pub session_text: Arc<Mutex<Vec<String>>>, // logged entries of the session, for export
+    pub input_level: AtomicU32, // RMS of the latest chunk as f32 bits, for the level meter
+    pub detected_language: Mutex<Option<String>>, // as reported by the server in auto-detect
+}
+
+// Takes the data out of a poisoned lock so a panic on one thread doesn't
+// cascade into every other user of the shared state:
+// `mutex.lock().unwrap_or_else(recover_poison)`
+pub fn recover_poison<T>(err: PoisonError<T>) -> T {
+    eprintln!("Recovered from a poisoned lock after a thread panicked");
+    err.into_inner()
+}
+
+// A failed API call as shown on the overlay
+#[derive(Clone, Copy)]
+pub struct ApiError {
+    pub purpose: &'static str, // "Transcription", "Translation", "Refine" or "Cleanup"
+    pub failure: ApiFailure,
+}
+
+#[derive(Clone, Copy)]
+pub enum ApiFailure {
+    Http(u16), // non-success status, e.g. 401 for a bad key
+    Timeout,
+    Connection,
+    Request,         // any other transport error
+    InvalidResponse, // the body wasn't the expected JSON
+}
+
+// Finalized captions waiting for the one on screen to be readable
+#[derive(Default)]
+pub struct CaptionQueue {
+    pending: VecDeque<(String, Option<f32>, bool)>, // caption, confidence, finished
+    shown_at: Option<Instant>,
+    recent: VecDeque<String>, // finished captions still shown above the current one
+}
+
+impl WorkerStatus {
+    fn mark_rate_limited(&self, wait: Duration) {
+        let until = Instant::now() + wait;
+        *self.rate_limited_until.lock().unwrap_or_else(recover_poison) = Some(until);
+    }
+
+    // Shows a banner on the overlay until a later request for the same purpose succeeds
+    fn report_error(&self, purpose: &'static str, failure: ApiFailure) {
+        *self.api_error.lock().unwrap_or_else(recover_poison) = Some(ApiError { purpose, failure });
+    }
+
+    fn clear_error(&self, purpose: &str) {
+        let mut error = self.api_error.lock().unwrap_or_else(recover_poison);
+        if error.is_some_and(|e| e.purpose == purpose) {
+            *error = None;
+        }
+    }
+
+    // Blocks for a rate-limit backoff while the overlay shows the countdown
+    fn wait_rate_limit(&self, wait: Duration) -> bool {
+        self.mark_rate_limited(wait);
+        self.wait_unless_stopping(wait)
+    }
+
+    // Sleeps in short steps so closing the app isn't held up by a long
+    // backoff; false if the app is shutting down
+    fn wait_unless_stopping(&self, wait: Duration) -> bool {
+        let until = Instant::now() + wait;
+        while !self.stopping.load(Ordering::Relaxed) {
+            let now = Instant::now();
+            if now >= until {
+                return true;
+            }
+            thread::sleep((until - now).min(Duration::from_millis(100)));
+        }
+        false
+    }
+
+    // Shows a caption now, or queues it behind the current one when queueing
+    // is on. Past the queue limit the oldest pending captions are dropped.
+    // `finished` is false for a sentence still being built up from fragments,
+    // which rolling mode replaces in place rather than scrolling up.
+    fn publish_caption(
+        &self,
+        display: String,
+        confidence: Option<f32>,
+        finished: bool,
+        transcript: &Mutex<String>,
+        settings: &Settings,
+    ) {
+        let mut queue = self.captions.lock().unwrap_or_else(recover_poison);
+        if !settings.queue_captions {
+            queue.pending.clear();
+            self.show_caption(&mut queue, display, confidence, finished, transcript, settings);
+            return;
+        }
+        queue.pending.push_back((display, confidence, finished));
+        while queue.pending.len() > settings.max_queued_captions.max(1) {
+            if let Some((dropped, ..)) = queue.pending.pop_front() {
+                eprintln!("Caption queue full, dropped: {dropped}");
+            }
+        }
+        drop(queue);
+        self.release_caption(transcript, settings);
+    }
+
+    // Puts the next queued caption on screen once the current one has been
+    // shown for the minimum display time. Called every frame by the overlay.
+    pub fn release_caption(&self, transcript: &Mutex<String>, settings: &Settings) {
+        let mut queue = self.captions.lock().unwrap_or_else(recover_poison);
+        let min_display = Duration::from_millis(settings.min_display_ms as u64);
+        if queue.pending.is_empty()
+            || queue.shown_at.is_some_and(|t| t.elapsed() < min_display)
+        {
+            return;
+        }
+        if let Some((next, confidence, finished)) = queue.pending.pop_front() {
+            self.show_caption(&mut queue, next, confidence, finished, transcript, settings);
+        }
+    }
+
+    // Puts a caption on screen. With rolling_phrases > 1 the previous finished
+    // captions stay above it, newest at the bottom.
+    fn show_caption(
+        &self,
+        queue: &mut CaptionQueue,
+        caption: String,
+        confidence: Option<f32>,
+        finished: bool,
+        transcript: &Mutex<String>,
+        settings: &Settings,
+    ) {
+        let keep = (settings.rolling_phrases.max(1) - 1) as usize;
+        while queue.recent.len() > keep {
+            queue.recent.pop_front();
+        }
+        let mut shown: Vec<&str> = queue.recent.iter().map(String::as_str).collect();
+        shown.push(&caption);
+        let display = shown.join("\n");
+        if finished && keep > 0 {
+            queue.recent.push_back(caption);
+        }
+        queue.shown_at = Some(Instant::now());
+        *self.caption_confidence.lock().unwrap_or_else(recover_poison) = confidence;
+        *transcript.lock().unwrap_or_else(recover_poison) = display;
+    }
+
+    pub fn clear_captions(&self) {
+        let mut queue = self.captions.lock().unwrap_or_else(recover_poison);
+        queue.pending.clear();
+        queue.recent.clear();
+        queue.shown_at = None;
+        *self.caption_confidence.lock().unwrap_or_else(recover_poison) = None;
+    }
+
+    // Starts or stops the session. Every caller (overlay, hotkeys, control API,
+    // scheduler) goes through here so a stop always clears the caption
+    pub fn set_session_active(
+        &self,
+        session_active: &AtomicBool,
+        transcript: &Mutex<String>,
+        active: bool,
+    ) {
+        session_active.store(active, Ordering::Relaxed);
+        if !active {
+            self.clear_captions();
+            *transcript.lock().unwrap_or_else(recover_poison) = String::new();
+        }
+    }
+}
+
+// Wraps the upload body so bytes handed to reqwest are counted as sent
+struct ProgressReader {
+    inner: std::io::Cursor<Vec<u8>>,
+    status: Arc<WorkerStatus>,
+}
+
+impl Read for ProgressReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.status.upload_sent.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+// Container/codec of the uploaded clip (only WAV is encoded for now)
+const UPLOAD_EXTENSION: &str = "wav";
+const UPLOAD_MIME: &str = "audio/wav";
+
+// Multipart filename for the upload. Some servers route on the extension, so
+// an override always gets the codec extension, replacing any other one so the
+// name can't contradict the content and UPLOAD_MIME.
+fn upload_filename(settings: &Settings) -> String {
+    let name = settings.upload_filename.trim();
+    if name.is_empty() {
+        format!("audio.{UPLOAD_EXTENSION}")
+    } else {
+        std::path::Path::new(name)
+            .with_extension(UPLOAD_EXTENSION)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
 fn rms(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
@@ -43,261 +413,1580 @@ fn rms(samples: &[f32]) -> f32 {
     (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
 }
 
+// Caps the boost so a phrase that is mostly room noise isn't blown up
+const MAX_NORMALIZE_GAIN: f32 = 20.0;
+
+// Scales the phrase so its peak reaches `target` (0..1 of full scale). Only
+// the upload copy is normalized; VAD keeps working on the raw capture.
+fn normalize_peak(samples: &[f32], target: f32) -> Vec<f32> {
+    let peak = samples.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+    if peak <= f32::EPSILON {
+        return samples.to_vec();
+    }
+    let gain = (target / peak).min(MAX_NORMALIZE_GAIN);
+    samples.iter().map(|s| (s * gain).clamp(-1.0, 1.0)).collect()
+}
+
+// Gain limits for the AGC so it can't blow up room tone or mute the input
+const AGC_MIN_GAIN: f32 = 0.1;
+const AGC_MAX_GAIN: f32 = 10.0;
+
+// Automatic gain control applied to captured audio before VAD and encoding.
+// An envelope follower tracks the input level and the gain moves toward
+// target/level: quickly down (attack) and slowly up (release). Below the
+// silence threshold the gain is frozen so pauses aren't pumped up into speech.
+struct Agc {
+    envelope: f32,
+    gain: f32,
+}
+
+impl Default for Agc {
+    fn default() -> Self {
+        Self { envelope: 0.0, gain: 1.0 }
+    }
+}
+
+impl Agc {
+    fn process(&mut self, samples: &mut [f32], rate: u32, settings: &Settings) {
+        // One-pole smoothing coefficient for a time constant in ms
+        let coef = |ms: u32| 1.0 - (-1000.0 / (ms.max(1) as f32 * rate as f32)).exp();
+        let attack = coef(settings.agc_attack_ms);
+        let release = coef(settings.agc_release_ms);
+        for s in samples {
+            let level = s.abs();
+            let c = if level > self.envelope { attack } else { release };
+            self.envelope += (level - self.envelope) * c;
+            if self.envelope > settings.silence_threshold {
+                let desired =
+                    (settings.agc_target / self.envelope).clamp(AGC_MIN_GAIN, AGC_MAX_GAIN);
+                let c = if desired < self.gain { attack } else { release };
+                self.gain += (desired - self.gain) * c;
+            }
+            *s = (*s * self.gain).clamp(-1.0, 1.0);
+        }
+    }
+}
+
+// Query parameters whose values are masked in the debug log
+const SECRET_PARAMS: &[&str] = &["key", "api_key", "apikey", "token", "access_token"];
+
+fn redact_url(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let params: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((k, _)) if SECRET_PARAMS.contains(&k.to_ascii_lowercase().as_str()) => {
+                format!("{k}=REDACTED")
+            }
+            _ => pair.to_string(),
+        })
+        .collect();
+    format!("{base}?{}", params.join("&"))
+}
+
+// Appends one request/response record to debug.log when debug logging is on.
+// API keys travel in the Authorization header, which is never written.
+fn debug_log(
+    settings: &Settings,
+    url: &str,
+    outcome: &str,
+    elapsed: Duration,
+    request: &str,
+    response: Option<&str>,
+) {
+    if !settings.debug_logging {
+        return;
+    }
+    use std::io::Write;
+    let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(crate::settings::debug_log_path())
+    else {
+        return;
+    };
+    let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+    let _ = writeln!(
+        file,
+        "[{now}] POST {} -> {outcome} in {}ms",
+        redact_url(url),
+        elapsed.as_millis()
+    );
+    if settings.debug_log_bodies {
+        let _ = writeln!(file, "  request: {request}");
+        if let Some(body) = response {
+            let _ = writeln!(file, "  response: {body}");
+        }
+    }
+}
+
+// Decodes a response body without trusting the server's charset blindly.
+// Valid UTF-8 wins even when mislabeled (a latin-1 label on UTF-8 CJK text is
+// a common misconfiguration); otherwise the declared charset is used, and as a
+// last resort the bytes are decoded as lossy UTF-8.
+fn decode_body(resp: reqwest::blocking::Response) -> Option<String> {
+    let charset = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|ct| {
+            ct.split(';')
+                .filter_map(|p| p.trim().strip_prefix("charset="))
+                .next()
+                .map(|c| c.trim_matches('"').to_string())
+        });
+    let bytes = resp.bytes().ok()?;
+    if let Ok(text) = std::str::from_utf8(&bytes) {
+        return Some(text.to_string());
+    }
+    if let Some(encoding) = charset
+        .as_deref()
+        .and_then(|c| encoding_rs::Encoding::for_label(c.as_bytes()))
+    {
+        let (text, _, had_errors) = encoding.decode(&bytes);
+        if had_errors {
+            eprintln!("Response had invalid {} sequences", encoding.name());
+        }
+        return Some(text.into_owned());
+    }
+    eprintln!("Response is not valid UTF-8 and has no usable charset, decoding lossily");
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
 fn translate_text(
     client: &reqwest::blocking::Client,
+    status: &WorkerStatus,
     text: &str,
-    chat_api_url: &str,
-    chat_api_key: &str,
-    chat_model: &str,
-    target_language: &str,
+    settings: &Settings,
     history: &VecDeque<(String, String)>,
+    keep_numbers_verbatim: bool,
 ) -> Option<String> {
+    // A per-target override (e.g. honorific rules for ko/ja) replaces the default
+    let template = settings
+        .translation_prompts
+        .get(&settings.target_language)
+        .filter(|prompt| !prompt.trim().is_empty())
+        .map_or(DEFAULT_TRANSLATION_PROMPT, String::as_str);
+    let mut system_prompt = template.replace("{target}", &settings.target_language);
+    if let Some(glossary) = glossary_prompt(settings) {
+        system_prompt.push_str(&format!(" Keep these terms spelled exactly: {glossary}."));
+    }
+    if let Some(rules) = term_pair_prompt(settings) {
+        system_prompt.push(' ');
+        system_prompt.push_str(&rules);
+    }
+    if keep_numbers_verbatim {
+        system_prompt.push_str(" Leave all numbers, equations, symbols and units exactly as written; do not spell them out or convert them.");
+    }
     let mut messages = vec![serde_json::json!({
         "role": "system",
-        "content": format!(
-            "You are a real-time translator for a scientific presentation. Translate the following spoken text into {}. Preserve technical and scientific terminology accurately. Output only a single, most probable translation. Print only the translated text and absolutely nothing else—no alternatives, no explanations, no notes, no quotation marks.",
-            target_language
-        )
+        "content": system_prompt
     })];
 
-    // Include previous transcription/translation pairs as context
-    for (orig, translated) in history {
-        messages.push(serde_json::json!({"role": "user", "content": orig}));
-        messages.push(serde_json::json!({"role": "assistant", "content": translated}));
+    // Include previous transcription/translation pairs as context. Some models
+    // drift when fed their own output, so the translations can be left out and
+    // the prior source given as a context paragraph instead.
+    if settings.history_includes_translation {
+        for (orig, translated) in history {
+            messages.push(serde_json::json!({"role": "user", "content": orig}));
+            messages.push(serde_json::json!({"role": "assistant", "content": translated}));
+        }
+    } else if !history.is_empty() {
+        let context: Vec<&str> = history.iter().map(|(orig, _)| orig.as_str()).collect();
+        messages.push(serde_json::json!({
+            "role": "system",
+            "content": format!(
+                "For context only (do not translate this), the speaker just said: {}",
+                context.join(" ")
+            )
+        }));
     }
 
     messages.push(serde_json::json!({"role": "user", "content": text}));
 
+    chat_completion(client, status, settings, &settings.chat_model, messages, "Translation")
+}
+
+// Second pass for high-stakes translation: a (usually stronger) model reviews
+// the draft against the source and returns a corrected version
+fn refine_translation(
+    client: &reqwest::blocking::Client,
+    status: &WorkerStatus,
+    source: &str,
+    draft: &str,
+    settings: &Settings,
+) -> Option<String> {
+    let system_prompt = format!(
+        "You review real-time translations into {}. You are given the spoken source text and a draft translation. Correct any mistranslations, omissions or terminology errors in the draft, keeping it natural and concise. Print only the corrected translation and absolutely nothing else—no explanations, no notes, no quotation marks.",
+        settings.target_language
+    );
+    let messages = vec![
+        serde_json::json!({"role": "system", "content": system_prompt}),
+        serde_json::json!({
+            "role": "user",
+            "content": format!("Source:\n{source}\n\nDraft translation:\n{draft}")
+        }),
+    ];
+    chat_completion(client, status, settings, &settings.refine_model, messages, "Refine")
+}
+
+// Light post-transcription pass: drop fillers/disfluencies and fix punctuation
+// without translating. Uses the same chat endpoint and model as translation.
+fn cleanup_text(
+    client: &reqwest::blocking::Client,
+    status: &WorkerStatus,
+    text: &str,
+    settings: &Settings,
+) -> Option<String> {
+    let messages = vec![
+        serde_json::json!({"role": "system", "content": settings.cleanup_prompt}),
+        serde_json::json!({"role": "user", "content": text}),
+    ];
+    chat_completion(client, status, settings, &settings.chat_model, messages, "Cleanup")
+}
+
+// Coarse category for the overlay banner: enough to tell a network problem
+// from a misconfigured endpoint
+fn request_failure(e: &reqwest::Error) -> ApiFailure {
+    if e.is_timeout() {
+        ApiFailure::Timeout
+    } else if e.is_connect() {
+        ApiFailure::Connection
+    } else {
+        ApiFailure::Request
+    }
+}
+
+// Sends a chat completions request and returns the trimmed reply, if any
+fn chat_completion(
+    client: &reqwest::blocking::Client,
+    status: &WorkerStatus,
+    settings: &Settings,
+    model: &str,
+    messages: Vec<serde_json::Value>,
+    purpose: &'static str,
+) -> Option<String> {
     let body = serde_json::json!({
-        "model": chat_model,
+        "model": model,
         "messages": messages
     });
+    let request_body = body.to_string();
 
-    let mut req = client
-        .post(chat_api_url)
-        .header("Content-Type", "application/json");
-    if !chat_api_key.is_empty() {
-        req = req.bearer_auth(chat_api_key);
+    let first_sent = Instant::now();
+    for attempt in 0.. {
+        let mut req = client
+            .post(&settings.chat_api_url)
+            .header("Content-Type", "application/json");
+        if !settings.chat_api_key.is_empty() {
+            req = req.bearer_auth(&settings.chat_api_key);
+        }
+        let started = Instant::now();
+        match req.body(request_body.clone()).send() {
+            Ok(resp) => {
+                let code = resp.status();
+                // Throttled: wait it out, unless the source text should be shown
+                // instead (a failed chat call already falls back to it)
+                let backoff = rate_limit_backoff(&resp, attempt, settings);
+                let body = decode_body(resp);
+                debug_log(
+                    settings,
+                    &settings.chat_api_url,
+                    code.as_str(),
+                    started.elapsed(),
+                    &request_body,
+                    body.as_deref(),
+                );
+                if let Some(wait) = backoff {
+                    if settings.rate_limit_show_source || attempt >= RATE_LIMIT_RETRIES {
+                        eprintln!("{purpose} rate limited");
+                        status.mark_rate_limited(wait);
+                        return None;
+                    }
+                    eprintln!("{purpose} rate limited, retrying in {}s", wait.as_secs());
+                    if !status.wait_rate_limit(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                if is_transient_status(code)
+                    && let Some(wait) =
+                        transient_backoff(attempt, first_sent, settings.chat_timeout_secs, settings)
+                {
+                    eprintln!("{purpose} failed: HTTP {code}, retrying in {}ms", wait.as_millis());
+                    if !status.wait_unless_stopping(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                if !code.is_success() {
+                    eprintln!("{purpose} failed: HTTP {code}");
+                    status.report_error(purpose, ApiFailure::Http(code.as_u16()));
+                    return None;
+                }
+                if let Some(body) = body
+                    && let Ok(json) = serde_json::from_str::<serde_json::Value>(&body)
+                    && let Some(content) = json["choices"][0]["message"]["content"].as_str()
+                {
+                    status.clear_error(purpose);
+                    let reply = content.trim().to_string();
+                    if !reply.is_empty() {
+                        return Some(reply);
+                    }
+                    return None;
+                }
+                eprintln!("{purpose} failed: unreadable response");
+                status.report_error(purpose, ApiFailure::InvalidResponse);
+                return None;
+            }
+            Err(e) => {
+                eprintln!("{purpose} error: {e}");
+                debug_log(
+                    settings,
+                    &settings.chat_api_url,
+                    &format!("error: {e}"),
+                    started.elapsed(),
+                    &request_body,
+                    None,
+                );
+                if is_transient_error(&e)
+                    && let Some(wait) =
+                        transient_backoff(attempt, first_sent, settings.chat_timeout_secs, settings)
+                {
+                    if !status.wait_unless_stopping(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                status.report_error(purpose, request_failure(&e));
+                return None;
+            }
+        }
     }
-    match req
-        .body(body.to_string())
-        .send()
-    {
-        Ok(resp) => {
-            if let Ok(body) = resp.text() {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) {
-                    if let Some(content) = json["choices"][0]["message"]["content"].as_str() {
-                        let translated = content.trim().to_string();
-                        if !translated.is_empty() {
-                            return Some(translated);
-                        }
+    None
+}
+
+// Retries after a 429 before giving up on a request
+const RATE_LIMIT_RETRIES: usize = 2;
+
+// Server errors and dropped connections are retried up to
+// settings.transient_retries times; 400/401 and the like fail straight away
+// since repeating them can't help
+const TRANSIENT_BACKOFF_MS: u64 = 200; // doubled per attempt, plus jitter
+
+fn is_transient_status(code: reqwest::StatusCode) -> bool {
+    matches!(code.as_u16(), 500 | 502 | 503 | 504)
+}
+
+// Refused/reset connections; a timeout is not retried since the request may
+// well have reached a slow server and repeating it would double the wait
+fn is_transient_error(e: &reqwest::Error) -> bool {
+    if e.is_connect() {
+        return true;
+    }
+    let mut source = std::error::Error::source(e);
+    while let Some(err) = source {
+        if let Some(io) = err.downcast_ref::<std::io::Error>()
+            && matches!(
+                io.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+            )
+        {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+// Exponential backoff (200, 400, 800 ms, ...) with up to 50 ms of jitter so
+// several clients hitting the same server don't retry in lockstep. None once
+// the retries are used up or the wait would run past the client timeout
+// counted from the first attempt, so retrying never stretches a request
+// beyond it.
+fn transient_backoff(
+    attempt: usize,
+    first_sent: Instant,
+    timeout_secs: u64,
+    settings: &Settings,
+) -> Option<Duration> {
+    if attempt >= settings.transient_retries as usize {
+        return None;
+    }
+    let jitter = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos() as u64 % 50);
+    let wait = Duration::from_millis((TRANSIENT_BACKOFF_MS << attempt.min(6)) + jitter);
+    (first_sent.elapsed() + wait < Duration::from_secs(timeout_secs)).then_some(wait)
+}
+
+// For a 429 response, how long to back off: Retry-After (seconds or an HTTP
+// date) when given, else exponential from 1s, capped at max_backoff_secs
+fn rate_limit_backoff(
+    resp: &reqwest::blocking::Response,
+    attempt: usize,
+    settings: &Settings,
+) -> Option<Duration> {
+    if resp.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let retry_after = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| {
+            v.trim().parse::<u64>().ok().or_else(|| {
+                let at = chrono::DateTime::parse_from_rfc2822(v.trim()).ok()?;
+                Some((at.timestamp() - chrono::Utc::now().timestamp()).max(0) as u64)
+            })
+        });
+    let secs = retry_after.unwrap_or(1 << attempt.min(6));
+    Some(Duration::from_secs(secs.min(settings.max_backoff_secs)))
+}
+
+// Transcription uploads and chat calls have very different latency profiles,
+// so each gets its own client and timeout
+struct HttpClients {
+    transcribe: reqwest::blocking::Client,
+    chat: reqwest::blocking::Client,
+    options: ClientOptions,
+}
+
+// Settings the clients are built from; a change rebuilds them
+#[derive(PartialEq)]
+struct ClientOptions {
+    transcribe_timeout_secs: u64,
+    chat_timeout_secs: u64,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout_secs: u64,
+    http2: bool,
+}
+
+impl ClientOptions {
+    fn from_settings(settings: &Settings) -> Self {
+        Self {
+            transcribe_timeout_secs: settings.transcribe_timeout_secs,
+            chat_timeout_secs: settings.chat_timeout_secs,
+            pool_max_idle_per_host: settings.pool_max_idle_per_host,
+            pool_idle_timeout_secs: settings.pool_idle_timeout_secs,
+            http2: settings.http2,
+        }
+    }
+}
+
+impl HttpClients {
+    fn new(settings: &Settings) -> Self {
+        let options = ClientOptions::from_settings(settings);
+        let build = |secs: u64| {
+            // Keeping idle connections around lets successive phrases skip the
+            // TCP/TLS handshake; HTTP/2 is negotiated via ALPN when offered
+            let builder = reqwest::blocking::Client::builder()
+                .timeout(Duration::from_secs(secs))
+                .pool_max_idle_per_host(options.pool_max_idle_per_host)
+                .pool_idle_timeout(Duration::from_secs(options.pool_idle_timeout_secs));
+            let builder = if options.http2 { builder } else { builder.http1_only() };
+            builder.build().expect("Failed to build HTTP client")
+        };
+        Self {
+            transcribe: build(options.transcribe_timeout_secs),
+            chat: build(options.chat_timeout_secs),
+            options,
+        }
+    }
+
+    // Rebuilds the clients when the connection settings change
+    fn refresh(&mut self, settings: &Settings) {
+        if self.options != ClientOptions::from_settings(settings) {
+            *self = Self::new(settings);
+        }
+    }
+}
+
+struct LogEntry {
+    time: String,
+    source: String,
+    translation: Option<String>,
+    span: Option<(f32, f32)>, // start/end in seconds since the session started
+}
+
+// Cue timestamp: 00:01:02,345 for SRT, 00:01:02.345 for WebVTT
+fn cue_timestamp(secs: f32, offset_ms: i32, format: SubtitleFormat) -> String {
+    // Shifted cues can't start before the recording does
+    let ms = (secs * 1000.0 + offset_ms as f32).max(0.0).round() as u64;
+    let separator = if format == SubtitleFormat::Vtt { '.' } else { ',' };
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+// Per-session state carried across phrases by the transcription thread
+#[derive(Default)]
+struct SessionState {
+    history: VecDeque<(String, String)>, // recent (source, translation) pairs
+    log_file: Option<std::io::BufWriter<std::fs::File>>, // flushed per log_flush_mode
+    last_flush: Option<Instant>,
+    last_text: String,       // source text of the previous phrase
+    overlaps_previous: bool, // next phrase starts with audio carried from the last one
+    replaces_previous: bool, // next phrase re-transcribes the previous, shown fragment too
+    gap_secs: Option<f32>,   // silence since the previous phrase ended
+    sentence: String,        // fragments awaiting sentence-end punctuation
+    sentence_started: Option<Instant>,
+    log_prefix: String, // session_<timestamp>, shared by all parts
+    log_part: u32,
+    log_opened: Option<Instant>,
+    phrase_count: u64,
+    pending_entry: Option<LogEntry>, // held back by smart_merge_sentences
+    confidence: Option<f32>,         // lowest phrase confidence in the pending caption
+    compare_results: Arc<Mutex<Vec<(u64, String)>>>, // filled by compare_transcription
+    session_text: Arc<Mutex<Vec<String>>>,           // WorkerStatus::session_text
+    subtitle_file: Option<(std::io::BufWriter<std::fs::File>, SubtitleFormat)>,
+    cue_count: u64,
+    phrase_span: Option<(f32, f32)>, // of the phrase being transcribed, see LogEntry
+    sentence_start: Option<f32>,     // span start of the first buffered sentence fragment
+}
+
+impl SessionState {
+    // Part 1 is session_<ts>.txt; later parts add _partN so they can be
+    // stitched back together by the common prefix
+    fn log_filename(&self) -> String {
+        if self.log_part <= 1 {
+            format!("{}.txt", self.log_prefix)
+        } else {
+            format!("{}_part{}.txt", self.log_prefix, self.log_part)
+        }
+    }
+
+    fn open_log(&mut self) {
+        let path = crate::settings::sessions_dir().join(self.log_filename());
+        match std::fs::File::create(path) {
+            Ok(f) => self.log_file = Some(std::io::BufWriter::new(f)),
+            Err(e) => eprintln!("Failed to create session log: {e}"),
+        }
+        self.log_opened = Some(Instant::now());
+    }
+
+    // A buffered sentence is cued from its first fragment to the latest one
+    fn widen_span_to_sentence(&mut self) {
+        if let (Some(start), Some((span_start, _))) =
+            (self.sentence_start.take(), &mut self.phrase_span)
+        {
+            *span_start = start;
+        }
+    }
+
+    // One subtitle file per session, not per part: cue times run from the
+    // session start, so splitting it would only make it harder to use
+    fn open_subtitles(&mut self, format: SubtitleFormat) {
+        use std::io::Write;
+        self.subtitle_file = None;
+        self.cue_count = 0;
+        let Some(extension) = format.extension() else {
+            return;
+        };
+        let path = crate::settings::sessions_dir().join(format!("{}.{extension}", self.log_prefix));
+        match std::fs::File::create(path) {
+            Ok(f) => {
+                let mut file = std::io::BufWriter::new(f);
+                if format == SubtitleFormat::Vtt {
+                    let _ = writeln!(file, "WEBVTT\n");
+                }
+                self.subtitle_file = Some((file, format));
+            }
+            Err(e) => eprintln!("Failed to create subtitle file: {e}"),
+        }
+    }
+
+    fn write_cue(&mut self, settings: &Settings, entry: &LogEntry) {
+        use std::io::Write;
+        let (Some((file, format)), Some((start, end))) = (&mut self.subtitle_file, entry.span)
+        else {
+            return;
+        };
+        let text = match (settings.subtitle_content, &entry.translation) {
+            (SubtitleContent::Source, _) | (SubtitleContent::Translation, None) => {
+                entry.source.clone()
+            }
+            (SubtitleContent::Translation, Some(translation)) => translation.clone(),
+            (SubtitleContent::Both, Some(translation)) => {
+                format!("{}\n{translation}", entry.source)
+            }
+            (SubtitleContent::Both, None) => entry.source.clone(),
+        };
+        self.cue_count += 1;
+        if *format == SubtitleFormat::Srt {
+            let _ = writeln!(file, "{}", self.cue_count);
+        }
+        let _ = writeln!(
+            file,
+            "{} --> {}\n{text}\n",
+            cue_timestamp(start, settings.subtitle_offset_ms, *format),
+            cue_timestamp(end.max(start), settings.subtitle_offset_ms, *format)
+        );
+        if settings.log_flush_mode == LogFlushMode::EveryLine {
+            let _ = file.flush();
+        }
+    }
+
+    // Logs a finished phrase. With smart_merge_sentences the entry is held back
+    // until the next phrase shows whether it continues the same sentence (no
+    // end punctuation, next one starts lowercase), in which case they're joined.
+    fn log_phrase(
+        &mut self,
+        settings: &Settings,
+        source: &str,
+        translation: Option<&str>,
+        turn_gap: Option<f32>,
+    ) {
+        if settings.smart_merge_sentences
+            && turn_gap.is_none()
+            && let Some(pending) = &mut self.pending_entry
+            && !ends_sentence(&pending.source)
+            && source.chars().next().is_some_and(char::is_lowercase)
+        {
+            pending.source.push(' ');
+            pending.source.push_str(source);
+            if let (Some((_, end)), Some((_, next_end))) = (&mut pending.span, self.phrase_span) {
+                *end = next_end;
+            }
+            match (&mut pending.translation, translation) {
+                (Some(joined), Some(next)) => {
+                    joined.push(' ');
+                    joined.push_str(next);
+                }
+                (joined @ None, Some(next)) => *joined = Some(next.to_string()),
+                _ => {}
+            }
+            return;
+        }
+
+        self.flush_pending_log(settings);
+        if let Some(gap) = turn_gap
+            && let Some(file) = &mut self.log_file
+        {
+            use std::io::Write;
+            let _ = writeln!(file, "--- (gap {}s) ---", gap.round() as u64);
+        }
+        let entry = LogEntry {
+            time: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            source: source.to_string(),
+            translation: translation.map(str::to_string),
+            span: self.phrase_span,
+        };
+        if settings.smart_merge_sentences {
+            self.pending_entry = Some(entry);
+        } else {
+            self.write_log_entry(settings, &entry);
+        }
+    }
+
+    // Writes out an entry held back for merging (before rotating or closing)
+    fn flush_pending_log(&mut self, settings: &Settings) {
+        if let Some(entry) = self.pending_entry.take() {
+            self.write_log_entry(settings, &entry);
+        }
+    }
+
+    fn write_log_entry(&mut self, settings: &Settings, entry: &LogEntry) {
+        use std::io::Write;
+        let mut text = format!("[{}] {}", entry.time, entry.source);
+        if let Some(translation) = &entry.translation {
+            text.push_str(&format!("\n[{}] {}", entry.time, translation));
+        }
+        self.session_text.lock().unwrap_or_else(recover_poison).push(text);
+        self.write_cue(settings, entry);
+        let Some(file) = &mut self.log_file else {
+            return;
+        };
+        let _ = writeln!(file, "[{}] {}", entry.time, entry.source);
+        if let Some(translation) = &entry.translation {
+            let _ = writeln!(file, "[{}] {}", entry.time, translation);
+        }
+        let _ = writeln!(file, "---");
+        if settings.log_flush_mode == LogFlushMode::EveryLine {
+            let _ = file.flush();
+        }
+    }
+
+    // Writes comparison transcriptions that finished since the last poll
+    fn log_comparisons(&mut self) {
+        use std::io::Write;
+        let results =
+            std::mem::take(&mut *self.compare_results.lock().unwrap_or_else(recover_poison));
+        if let Some(file) = &mut self.log_file {
+            let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            for (id, text) in results {
+                let _ = writeln!(file, "[{now}] [compare #{id}] {text}");
+            }
+        }
+    }
+
+    // Closes the current part with a footer and continues in the next one
+    fn rotate_log(&mut self) {
+        use std::io::Write;
+        let previous = self.log_filename();
+        self.log_part += 1;
+        let next = self.log_filename();
+        if let Some(mut file) = self.log_file.take() {
+            let _ = writeln!(file, "=== continued in {next} ===");
+            let _ = file.flush();
+        }
+        self.open_log();
+        if let Some(file) = &mut self.log_file {
+            let _ = writeln!(file, "=== continued from {previous} ===");
+        }
+    }
+}
+
+// Form fields sent with the clip, shared by both upload modes
+fn transcription_fields(
+    settings: &Settings,
+    language: Option<&str>,
+) -> Vec<(&'static str, String)> {
+    let mut fields = vec![("model", settings.transcribe_model().to_string())];
+    if let Some(language) = language {
+        fields.push(("language", language.to_string()));
+    }
+    if let Some(glossary) = glossary_prompt(settings) {
+        // Whisper's prompt biases recognition toward the spellings it contains
+        fields.push(("prompt", glossary));
+    }
+    if language.is_none() && settings.candidate_languages.len() > 1 {
+        // Hint for servers that accept a candidate set; others ignore it
+        fields.push(("candidate_languages", settings.candidate_languages.join(",")));
+    }
+    if language.is_none() || settings.confidence_coloring {
+        // verbose_json adds the detected language and per-segment log probabilities
+        fields.push(("response_format", "verbose_json".to_string()));
+    }
+    fields
+}
+
+// {"audio": "<base64 wav>", "model": ..., "language": ...} for JSON-only endpoints
+fn base64_json_body(wav: &[u8], settings: &Settings, language: Option<&str>) -> Vec<u8> {
+    use base64::Engine;
+    let mut body = serde_json::Map::new();
+    body.insert(
+        "audio".to_string(),
+        base64::engine::general_purpose::STANDARD.encode(wav).into(),
+    );
+    for (name, value) in transcription_fields(settings, language) {
+        body.insert(name.to_string(), value.into());
+    }
+    serde_json::Value::Object(body).to_string().into_bytes()
+}
+
+// Uploads one WAV clip and returns the parsed JSON response. `language` of
+// None lets the server auto-detect.
+fn request_transcription(
+    clients: &HttpClients,
+    status: &Arc<WorkerStatus>,
+    wav: Vec<u8>,
+    settings: &Settings,
+    language: Option<&str>,
+) -> Option<serde_json::Value> {
+    let wav_len = wav.len() as u64;
+    // Base64 inflates the clip by a third; encode once and reuse across retries
+    let json_body = (settings.upload_mode == UploadMode::Base64Json)
+        .then(|| base64_json_body(&wav, settings, language));
+    let body_len = json_body.as_ref().map_or(wav_len, |body| body.len() as u64);
+    let request_desc = match json_body {
+        Some(_) => format!(
+            "base64 JSON ({body_len} bytes), language={}",
+            language.unwrap_or("auto")
+        ),
+        None => format!(
+            "multipart {} ({wav_len} bytes), language={}",
+            upload_filename(settings),
+            language.unwrap_or("auto")
+        ),
+    };
+
+    let first_sent = Instant::now();
+    for attempt in 0.. {
+        status.upload_sent.store(0, Ordering::Relaxed);
+        status.upload_total.store(body_len, Ordering::Relaxed);
+        let mut req = clients.transcribe.post(&settings.api_url);
+        if !settings.api_key.is_empty() {
+            req = req.bearer_auth(&settings.api_key);
+        }
+        req = match &json_body {
+            Some(json) => {
+                let body = ProgressReader {
+                    inner: std::io::Cursor::new(json.clone()),
+                    status: status.clone(),
+                };
+                req.header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(reqwest::blocking::Body::sized(body, body_len))
+            }
+            None => {
+                let body = ProgressReader {
+                    inner: std::io::Cursor::new(wav.clone()),
+                    status: status.clone(),
+                };
+                let mut form = reqwest::blocking::multipart::Form::new().part(
+                    "file",
+                    reqwest::blocking::multipart::Part::reader_with_length(body, wav_len)
+                        .file_name(upload_filename(settings))
+                        .mime_str(UPLOAD_MIME)
+                        .unwrap(),
+                );
+                for (name, value) in transcription_fields(settings, language) {
+                    form = form.text(name, value);
+                }
+                req.multipart(form)
+            }
+        };
+        let started = Instant::now();
+        match req.send() {
+            Ok(resp) => {
+                let code = resp.status();
+                let backoff = rate_limit_backoff(&resp, attempt, settings);
+                let body = decode_body(resp);
+                debug_log(
+                    settings,
+                    &settings.api_url,
+                    code.as_str(),
+                    started.elapsed(),
+                    &request_desc,
+                    body.as_deref(),
+                );
+                if let Some(wait) = backoff {
+                    if attempt >= RATE_LIMIT_RETRIES {
+                        // Show the backoff and flag the dropped phrase on the overlay
+                        eprintln!("Transcription rate limited, dropping phrase");
+                        status.mark_rate_limited(wait);
+                        status.report_error("Transcription", ApiFailure::Http(code.as_u16()));
+                        return None;
+                    }
+                    eprintln!("Transcription rate limited, retrying in {}s", wait.as_secs());
+                    if !status.wait_rate_limit(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                if is_transient_status(code)
+                    && let Some(wait) = transient_backoff(
+                        attempt,
+                        first_sent,
+                        settings.transcribe_timeout_secs,
+                        settings,
+                    )
+                {
+                    eprintln!(
+                        "Transcription failed: HTTP {code}, retrying in {}ms",
+                        wait.as_millis()
+                    );
+                    if !status.wait_unless_stopping(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                if !code.is_success() {
+                    eprintln!("Transcription failed: HTTP {code}");
+                    status.report_error("Transcription", ApiFailure::Http(code.as_u16()));
+                    return None;
+                }
+                let json = body.and_then(|body| serde_json::from_str(&body).ok());
+                match &json {
+                    Some(_) => status.clear_error("Transcription"),
+                    None => {
+                        eprintln!("Transcription failed: unreadable response");
+                        status.report_error("Transcription", ApiFailure::InvalidResponse);
                     }
                 }
+                return json;
+            }
+            Err(e) => {
+                eprintln!("Transcription error: {e}");
+                debug_log(
+                    settings,
+                    &settings.api_url,
+                    &format!("error: {e}"),
+                    started.elapsed(),
+                    &request_desc,
+                    None,
+                );
+                if is_transient_error(&e)
+                    && let Some(wait) = transient_backoff(
+                        attempt,
+                        first_sent,
+                        settings.transcribe_timeout_secs,
+                        settings,
+                    )
+                {
+                    if !status.wait_unless_stopping(wait) {
+                        return None;
+                    }
+                    continue;
+                }
+                status.report_error("Transcription", request_failure(&e));
+                return None;
             }
-            None
         }
-        Err(e) => {
-            eprintln!("Translation error: {e}");
-            None
+    }
+    None
+}
+
+// Sends the clip to the comparison endpoint on its own thread so the primary
+// caption never waits for it; the result is picked up by log_comparisons
+fn compare_transcription(
+    clients: &HttpClients,
+    wav: Vec<u8>,
+    settings: &Settings,
+    language: Option<String>,
+    id: u64,
+    results: Arc<Mutex<Vec<(u64, String)>>>,
+) {
+    let client = clients.transcribe.clone();
+    let settings = settings.clone();
+    thread::spawn(move || {
+        let request_desc = format!(
+            "multipart {} ({} bytes), language={}, compare #{id}",
+            upload_filename(&settings),
+            wav.len(),
+            language.as_deref().unwrap_or("auto")
+        );
+        let mut form = reqwest::blocking::multipart::Form::new().part(
+            "file",
+            reqwest::blocking::multipart::Part::bytes(wav)
+                .file_name(upload_filename(&settings))
+                .mime_str(UPLOAD_MIME)
+                .unwrap(),
+        );
+        for (name, value) in transcription_fields(&settings, language.as_deref()) {
+            form = form.text(name, value);
+        }
+        let mut req = client.post(&settings.compare_api_url);
+        if !settings.compare_api_key.is_empty() {
+            req = req.bearer_auth(&settings.compare_api_key);
+        }
+        let started = Instant::now();
+        let text = match req.multipart(form).send() {
+            Ok(resp) => {
+                let code = resp.status();
+                let body = decode_body(resp);
+                debug_log(
+                    &settings,
+                    &settings.compare_api_url,
+                    code.as_str(),
+                    started.elapsed(),
+                    &request_desc,
+                    body.as_deref(),
+                );
+                body.and_then(|body| serde_json::from_str::<serde_json::Value>(&body).ok())
+                    .and_then(|json| json["text"].as_str().map(|t| t.trim().to_string()))
+                    .unwrap_or_else(|| format!("(HTTP {code}, no text)"))
+            }
+            Err(e) => {
+                debug_log(
+                    &settings,
+                    &settings.compare_api_url,
+                    &format!("error: {e}"),
+                    started.elapsed(),
+                    &request_desc,
+                    None,
+                );
+                format!("(error: {e})")
+            }
+        };
+        results.lock().unwrap_or_else(recover_poison).push((id, text));
+    });
+}
+
+// Phrase confidence 0..1 from verbose_json segments (mean avg_logprob, as a
+// probability). None if the server didn't return segments.
+fn phrase_confidence(json: &serde_json::Value) -> Option<f32> {
+    let logprobs: Vec<f64> = json["segments"]
+        .as_array()?
+        .iter()
+        .filter_map(|segment| segment["avg_logprob"].as_f64())
+        .collect();
+    if logprobs.is_empty() {
+        return None;
+    }
+    let mean = logprobs.iter().sum::<f64>() / logprobs.len() as f64;
+    Some(mean.exp().clamp(0.0, 1.0) as f32)
+}
+
+// Whether a phrase starting at `phrase_start` continues the previous phrase,
+// a short fragment that ended at `fragment_end` (positions in samples), so
+// the two are re-transcribed together
+fn merges_with_fragment(
+    fragment: &[f32],
+    fragment_end: usize,
+    phrase_start: usize,
+    rate: u32,
+    settings: &Settings,
+) -> bool {
+    let max_gap = rate as usize * settings.merge_gap_ms as usize / 1000;
+    settings.merge_fragments
+        && fragment.len() as f32 <= rate as f32 * MERGE_FRAGMENT_SECS
+        && phrase_start.saturating_sub(fragment_end) <= max_gap
+}
+
+// Whether a detected language (code like "ko" or name like "korean", depending
+// on the server) is one of the configured candidates
+fn is_candidate_language(detected: &str, candidates: &[String]) -> bool {
+    candidates.iter().any(|code| {
+        code.eq_ignore_ascii_case(detected)
+            || language_name(SOURCE_LANGUAGES, code).eq_ignore_ascii_case(detected)
+    })
+}
+
+// The part of a transcription that is new relative to the previous phrase,
+// or None to drop it. A merged re-transcription (heard with the fragment's
+// audio for context) keeps only what follows the fragment, which is already
+// logged, cued and on screen; an echoed phrase is skipped or trimmed per
+// repeat_handling; audio carried over from a forced cut is stripped.
+fn new_source_text(
+    session: &mut SessionState,
+    settings: &Settings,
+    raw: &str,
+    replaces_previous: bool,
+) -> Option<String> {
+    let mut text = raw.trim().to_string();
+    if replaces_previous {
+        text = novel_suffix(&session.last_text, &text);
+    } else if settings.repeat_handling != RepeatHandling::Keep
+        && !session.last_text.is_empty()
+        && overlap_ratio(&session.last_text, &text) >= settings.repeat_similarity
+    {
+        // Whisper echoed the previous phrase; don't pay to translate it again
+        match settings.repeat_handling {
+            RepeatHandling::Skip => return None,
+            _ => text = novel_suffix(&session.last_text, &text),
         }
     }
+    if std::mem::take(&mut session.overlaps_previous) {
+        text = strip_overlap(&session.last_text, &text);
+    }
+    Some(text)
 }
 
+// Returns whether the phrase produced text (now in `session.last_text`)
 fn send_transcription(
-    client: &reqwest::blocking::Client,
+    clients: &HttpClients,
+    status: &Arc<WorkerStatus>,
     samples: &[f32],
     rate: u32,
     transcript: &Arc<Mutex<String>>,
-    api_url: &str,
-    api_key: &str,
-    language: &str,
-    chat_api_url: &str,
-    chat_api_key: &str,
-    chat_model: &str,
-    target_language: &str,
-    display_mode: &DisplayMode,
-    history: &mut VecDeque<(String, String)>,
-    log_file: &mut Option<std::fs::File>,
+    settings: &Settings,
+    session: &mut SessionState,
+) -> bool {
+    // Only the upload is resampled; VAD and the retro buffer stay at the device rate
+    let resampled = resample_to_16k(samples, rate, settings.resample_quality);
+    let wav = if settings.normalize_audio {
+        encode_wav(
+            &normalize_peak(&resampled, settings.normalize_target),
+            TRANSCRIBE_SAMPLE_RATE,
+        )
+    } else {
+        encode_wav(&resampled, TRANSCRIBE_SAMPLE_RATE)
+    };
+    // Taken before any early return so a failed send doesn't leave it set
+    // for the next, unrelated phrase
+    let replaces_previous = std::mem::take(&mut session.replaces_previous);
+    let candidates = &settings.candidate_languages;
+    session.phrase_count += 1;
+    let comparing = !settings.compare_api_url.is_empty();
+    if comparing {
+        let language = match candidates.as_slice() {
+            [] => settings.forced_language().map(str::to_string),
+            [only] => Some(only.clone()),
+            _ => None,
+        };
+        compare_transcription(
+            clients,
+            wav.clone(),
+            settings,
+            language,
+            session.phrase_count,
+            session.compare_results.clone(),
+        );
+    }
+    let json = match candidates.as_slice() {
+        [] => request_transcription(clients, status, wav, settings, settings.forced_language()),
+        [only] => request_transcription(clients, status, wav, settings, Some(only)),
+        [first, ..] => {
+            // Auto-detect among the candidates; if the server picked something
+            // else, transcribe again forcing the primary candidate
+            let json = request_transcription(clients, status, wav.clone(), settings, None);
+            match json.as_ref().and_then(|j| j["language"].as_str()) {
+                Some(detected) if !is_candidate_language(detected, candidates) => {
+                    request_transcription(clients, status, wav, settings, Some(first))
+                }
+                _ => json,
+            }
+        }
+    };
+    let Some(text) = json.as_ref().and_then(|j| j["text"].as_str()) else {
+        return false;
+    };
+    if let Some(detected) = json.as_ref().and_then(|j| j["language"].as_str()) {
+        let mut last = status.detected_language.lock().unwrap_or_else(recover_poison);
+        *last = Some(detected.to_string());
+    }
+    let confidence = json.as_ref().and_then(phrase_confidence);
+
+    let Some(mut text) = new_source_text(session, settings, text, replaces_previous) else {
+        return false;
+    };
+    if settings.cleanup_enabled
+        && !is_blank_text(&text, settings)
+        && let Some(cleaned) = cleanup_text(&clients.chat, status, &text, settings)
+    {
+        text = cleaned;
+    }
+    if is_blank_text(&text, settings) {
+        return false;
+    }
+    session.last_text = text.clone();
+    // A caption built from several fragments is only as sure as its weakest one
+    session.confidence = match (session.confidence, confidence) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+
+    // Sentence mode: show fragments right away but only translate once the
+    // sentence is complete (or times out, see flush_sentence)
+    if settings.translate_on_sentence && !settings.target_language.is_empty() {
+        if session.sentence.is_empty() {
+            session.sentence_start = session.phrase_span.map(|(start, _)| start);
+        } else {
+            session.sentence.push(' ');
+        }
+        session.sentence.push_str(&text);
+        if !ends_sentence(&session.sentence) {
+            session.sentence_started.get_or_insert_with(Instant::now);
+            status.publish_caption(
+                session.sentence.clone(),
+                session.confidence,
+                false,
+                transcript,
+                settings,
+            );
+            return true;
+        }
+        text = std::mem::take(&mut session.sentence);
+        session.sentence_started = None;
+        session.widen_span_to_sentence();
+    }
+    finish_phrase(clients, status, transcript, settings, session, text);
+    true
+}
+
+// Translates the buffered sentence even though it has no end punctuation yet
+fn flush_sentence(
+    clients: &HttpClients,
+    status: &Arc<WorkerStatus>,
+    transcript: &Arc<Mutex<String>>,
+    settings: &Settings,
+    session: &mut SessionState,
 ) {
-    let wav = encode_wav(samples, rate);
-    let form = reqwest::blocking::multipart::Form::new()
-        .part(
-            "file",
-            reqwest::blocking::multipart::Part::bytes(wav)
-                .file_name("audio.wav")
-                .mime_str("audio/wav")
-                .unwrap(),
+    session.sentence_started = None;
+    session.widen_span_to_sentence();
+    let text = std::mem::take(&mut session.sentence);
+    if !text.is_empty() {
+        finish_phrase(clients, status, transcript, settings, session, text);
+    }
+}
+
+// Translates, logs and displays a finished source phrase
+fn finish_phrase(
+    clients: &HttpClients,
+    status: &Arc<WorkerStatus>,
+    transcript: &Arc<Mutex<String>>,
+    settings: &Settings,
+    session: &mut SessionState,
+    text: String,
+) {
+    let mostly_numeric = numeric_ratio(&text) >= settings.numeric_ratio_threshold;
+    let maybe_translated = if settings.target_language.is_empty()
+        || (mostly_numeric && settings.numeric_phrase_mode == NumericPhraseMode::ShowSource)
+    {
+        None
+    } else {
+        translate_text(
+            &clients.chat,
+            status,
+            &text,
+            settings,
+            &session.history,
+            mostly_numeric && settings.numeric_phrase_mode == NumericPhraseMode::KeepVerbatim,
         )
-        .text("model", "large-v3")
-        .text("language", language.to_string());
-
-    let mut req = client.post(api_url);
-    if !api_key.is_empty() {
-        req = req.bearer_auth(api_key);
-    }
-    match req.multipart(form).send() {
-        Ok(resp) => {
-            if let Ok(body) = resp.text() {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) {
-                    if let Some(text) = json["text"].as_str() {
-                        let text = text.trim().to_string();
-                        if !text.is_empty() {
-                            let maybe_translated = if !target_language.is_empty() {
-                                translate_text(
-                                    client,
-                                    &text,
-                                    chat_api_url,
-                                    chat_api_key,
-                                    chat_model,
-                                    target_language,
-                                    history,
-                                )
-                            } else {
-                                None
-                            };
+        .map(|draft| {
+            if settings.refine_model.is_empty() {
+                return draft;
+            }
+            refine_translation(&clients.chat, status, &text, &draft, settings).unwrap_or(draft)
+        })
+        .map(|translated| {
+            let unquoted = strip_quotes(&translated, &settings.strip_quotes);
+            let cased = apply_casing(&unquoted, &settings.translation_casing);
+            apply_canonical_terms(&cased, glossary_terms(settings))
+        })
+    };
 
-                            // Log to session file
-                            if let Some(file) = log_file {
-                                use std::io::Write;
-                                let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                                let _ = writeln!(file, "[{}] {}", now, &text);
-                                if let Some(ref tr) = maybe_translated {
-                                    let _ = writeln!(file, "[{}] {}", now, tr);
-                                }
-                                let _ = writeln!(file, "---");
-                                let _ = file.flush();
-                            }
+    // Log to session file
+    let turn_gap = session
+        .gap_secs
+        .filter(|gap| settings.mark_turns && *gap >= settings.turn_gap_secs);
+    session.log_phrase(settings, &text, maybe_translated.as_deref(), turn_gap);
 
-                            // Build display string
-                            let display = if let Some(translated) = maybe_translated {
-                                history.push_back((text.clone(), translated.clone()));
-                                if history.len() > 3 {
-                                    history.pop_front();
-                                }
-                                match display_mode {
-                                    DisplayMode::TranslationOnly => translated,
-                                    DisplayMode::Both => {
-                                        format!("{text}\n{translated}")
-                                    }
-                                }
-                            } else {
-                                text
-                            };
-                            *transcript.lock().unwrap() = display;
-                        }
-                    }
-                }
+    // Build display string
+    let display = if let Some(translated) = maybe_translated {
+        session.history.push_back((text.clone(), translated.clone()));
+        while session.history.len() > settings.translation_context_pairs as usize {
+            session.history.pop_front();
+        }
+        match settings.display_mode {
+            DisplayMode::TranslationOnly => translated,
+            DisplayMode::Both => {
+                format!("{text}\n{translated}")
             }
         }
-        Err(e) => eprintln!("Transcription error: {e}"),
+    } else {
+        text
+    };
+    status.publish_caption(display, session.confidence.take(), true, transcript, settings);
+}
+
+// Writes the retro buffer to sessions/retro_<time>.wav
+fn save_retro_buffer(retro: &mut VecDeque<f32>, rate: u32) {
+    if retro.is_empty() {
+        return;
+    }
+    let name = format!("retro_{}.wav", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S"));
+    let path = crate::settings::sessions_dir().join(name);
+    if let Err(e) = std::fs::write(&path, encode_wav(retro.make_contiguous(), rate)) {
+        eprintln!("Failed to save retro audio: {e}");
+    }
+}
+
+// Set once from `--input-file <path>` to replay a WAV file instead of a device
+static INPUT_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_input_file(path: PathBuf) {
+    let _ = INPUT_FILE.set(path);
+}
+
+// Chunk the file is fed in, matching the device callback cadence closely enough
+const FILE_CHUNK_MS: u64 = 50;
+// Silence appended after the file so the final phrase is finalized
+const FILE_TAIL_SECS: u32 = 2;
+
+// Feeds a WAV file into the capture buffer at real-time pace, so the VAD,
+// transcription and translation run exactly as they would live
+fn feed_input_file(
+    path: &std::path::Path,
+    buf: &Mutex<Vec<f32>>,
+    sr: &Mutex<u32>,
+    run: &AtomicBool,
+) {
+    let reader = match hound::WavReader::open(path) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Failed to open input file {}: {e}", path.display());
+            return;
+        }
+    };
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => {
+            reader.into_samples::<f32>().filter_map(Result::ok).collect()
+        }
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .filter_map(Result::ok)
+                .map(|s| s as f32 / scale)
+                .collect()
+        }
+    };
+    let channels = spec.channels.max(1) as usize;
+    let mut mono: Vec<f32> = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    mono.extend(std::iter::repeat_n(0.0, (spec.sample_rate * FILE_TAIL_SECS) as usize));
+
+    *sr.lock().unwrap_or_else(recover_poison) = spec.sample_rate;
+    let chunk = (spec.sample_rate as u64 * FILE_CHUNK_MS / 1000).max(1) as usize;
+    for piece in mono.chunks(chunk) {
+        if !run.load(Ordering::Relaxed) {
+            return;
+        }
+        buf.lock().unwrap_or_else(recover_poison).extend_from_slice(piece);
+        thread::sleep(Duration::from_millis(FILE_CHUNK_MS));
+    }
+    eprintln!("Input file finished: {}", path.display());
+}
+
+// Device config to capture with: the default one, or the forced rate when the
+// device supports it in a sample format we can read
+fn input_config(device: &cpal::Device, force_rate: u32) -> Option<cpal::SupportedStreamConfig> {
+    let default = match device.default_input_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("No input config: {e}");
+            return None;
+        }
+    };
+    if force_rate == 0 || default.sample_rate().0 == force_rate {
+        return Some(default);
+    }
+    let rate = cpal::SampleRate(force_rate);
+    let forced = device.supported_input_configs().ok().and_then(|configs| {
+        configs
+            .filter(|c| c.min_sample_rate() <= rate && rate <= c.max_sample_rate())
+            .filter(|c| {
+                matches!(
+                    c.sample_format(),
+                    cpal::SampleFormat::F32 | cpal::SampleFormat::I16 | cpal::SampleFormat::U16
+                )
+            })
+            // Prefer the default's format and channel count
+            .max_by_key(|c| {
+                (
+                    c.sample_format() == default.sample_format(),
+                    c.channels() == default.channels(),
+                )
+            })
+            .map(|c| c.with_sample_rate(rate))
+    });
+    match forced {
+        Some(c) => Some(c),
+        None => {
+            eprintln!(
+                "Sample rate {force_rate} Hz not supported by the device, using {} Hz",
+                default.sample_rate().0
+            );
+            Some(default)
+        }
     }
 }
 
+const RECONNECT_DELAY_MS: u64 = 2000;
+const STALL_CHECK_SECS: u64 = 3; // a stream silent this long is rebuilt
+const DEFAULT_CHANGED: &str = "is no longer the default";
+
+// Raised by the capture callbacks so the capture thread can tell when the
+// stream died (device unplugged) or quietly stopped delivering audio
+#[derive(Default)]
+struct StreamHealth {
+    failed: AtomicBool,
+    delivered: AtomicBool,
+}
+
+// Capture stream for one sample type: converts with `to_f32` and averages
+// the channels down to mono into the shared buffer
+fn build_input_stream<T: cpal::SizedSample + Send + 'static>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    channels: usize,
+    buf: Arc<Mutex<Vec<f32>>>,
+    health: Arc<StreamHealth>,
+    to_f32: fn(T) -> f32,
+) -> Result<cpal::Stream, String> {
+    let data_health = health.clone();
+    device
+        .build_input_stream(
+            config,
+            move |data: &[T], _: &cpal::InputCallbackInfo| {
+                data_health.delivered.store(true, Ordering::Relaxed);
+                let mut b = buf.lock().unwrap_or_else(recover_poison);
+                for frame in data.chunks(channels.max(1)) {
+                    let sum: f32 = frame.iter().map(|&s| to_f32(s)).sum();
+                    b.push(sum / frame.len() as f32);
+                }
+            },
+            move |e| {
+                eprintln!("Audio error: {e}");
+                if matches!(e, cpal::StreamError::DeviceNotAvailable) {
+                    health.failed.store(true, Ordering::Relaxed);
+                }
+            },
+            None,
+        )
+        .map_err(|e| format!("Failed to build input stream: {e}"))
+}
+
+// Finds the named input device, or the default one when the name is empty or
+// the device is gone
+fn find_input_device(host: &cpal::Host, name: &str) -> Option<cpal::Device> {
+    if name.is_empty() {
+        return host.default_input_device();
+    }
+    host.input_devices()
+        .ok()
+        .and_then(|mut devs| devs.find(|d| d.name().map(|n| n == name).unwrap_or(false)))
+        .or_else(|| {
+            eprintln!("Device '{name}' not found, using default");
+            host.default_input_device()
+        })
+}
+
+// Opens and starts capture from the configured device, returning the stream
+// and the name of the device it ended up on
+fn open_input_stream(
+    host: &cpal::Host,
+    device_name: &str,
+    force_rate: u32,
+    buf: &Arc<Mutex<Vec<f32>>>,
+    sr: &Mutex<u32>,
+    health: &Arc<StreamHealth>,
+) -> Result<(cpal::Stream, String), String> {
+    let device = find_input_device(host, device_name)
+        .ok_or_else(|| "No audio input device found".to_string())?;
+    let supported = input_config(&device, force_rate)
+        .ok_or_else(|| "Audio input device has no usable config".to_string())?;
+
+    let channels = supported.channels() as usize;
+    let fmt = supported.sample_format();
+    let rate = supported.sample_rate().0;
+    let config: cpal::StreamConfig = supported.into();
+
+    let (b, h) = (buf.clone(), health.clone());
+    let stream = match fmt {
+        cpal::SampleFormat::F32 => {
+            build_input_stream(&device, &config, channels, b, h, |s: f32| s)
+        }
+        cpal::SampleFormat::I16 => {
+            build_input_stream(&device, &config, channels, b, h, |s: i16| {
+                s as f32 / 32768.0
+            })
+        }
+        cpal::SampleFormat::U16 => {
+            build_input_stream(&device, &config, channels, b, h, |s: u16| {
+                (s as f32 - 32768.0) / 32768.0
+            })
+        }
+        fmt => Err(format!("Unsupported audio format: {fmt:?}")),
+    }?;
+    stream.play().map_err(|e| format!("Failed to start audio stream: {e}"))?;
+
+    // A replacement device may run at another rate; drop what was captured
+    // at the old one so it isn't resampled wrongly
+    let mut b = buf.lock().unwrap_or_else(recover_poison);
+    let mut current = sr.lock().unwrap_or_else(recover_poison);
+    if *current != rate {
+        b.clear();
+        *current = rate;
+    }
+    Ok((stream, device.name().unwrap_or_default()))
+}
+
+// Blocks while the stream is healthy; returns why it needs rebuilding, or
+// None on shutdown. Following the system default also switches when the
+// default device changes
+fn watch_input_stream(
+    host: &cpal::Host,
+    device_name: &str,
+    opened: &str,
+    health: &StreamHealth,
+    run: &AtomicBool,
+) -> Option<&'static str> {
+    let mut last_check = Instant::now();
+    while run.load(Ordering::Relaxed) {
+        thread::sleep(Duration::from_millis(100));
+        if health.failed.load(Ordering::Relaxed) {
+            return Some("disconnected");
+        }
+        if last_check.elapsed() < Duration::from_secs(STALL_CHECK_SECS) {
+            continue;
+        }
+        last_check = Instant::now();
+        if !health.delivered.swap(false, Ordering::Relaxed) {
+            return Some("stopped delivering audio");
+        }
+        if device_name.is_empty()
+            && let Some(default) = host.default_input_device().and_then(|d| d.name().ok())
+            && default != opened
+        {
+            return Some(DEFAULT_CHANGED);
+        }
+    }
+    None
+}
+
 pub fn start_audio_and_transcription(
     transcript: Arc<Mutex<String>>,
     running: Arc<AtomicBool>,
     settings: Arc<Mutex<Settings>>,
     session_active: Arc<AtomicBool>,
+    status: Arc<WorkerStatus>,
 ) {
     let audio_buffer: Arc<Mutex<Vec<f32>>> = Arc::new(Mutex::new(Vec::new()));
     let sample_rate: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
 
     // Audio capture thread
-    {
+    if let Some(path) = INPUT_FILE.get() {
+        let buf = audio_buffer.clone();
+        let sr = sample_rate.clone();
+        let run = running.clone();
+        let path = path.clone();
+        thread::spawn(move || feed_input_file(&path, &buf, &sr, &run));
+    } else {
         let buf = audio_buffer.clone();
         let sr = sample_rate.clone();
         let run = running.clone();
-        let input_device_name = settings.lock().unwrap().input_device.clone();
+        let transcript = transcript.clone();
+        let (host_name, input_device_name, force_sample_rate) = {
+            let s = settings.lock().unwrap_or_else(recover_poison);
+            (s.audio_host.clone(), s.input_device.clone(), s.force_sample_rate)
+        };
         thread::spawn(move || {
-            let host = cpal::default_host();
-            let device = if input_device_name.is_empty() {
-                host.default_input_device()
-            } else {
-                use cpal::traits::HostTrait;
-                host.input_devices()
-                    .ok()
-                    .and_then(|mut devs| {
-                        devs.find(|d| {
-                            d.name().map(|n| n == input_device_name).unwrap_or(false)
-                        })
-                    })
-                    .or_else(|| {
-                        eprintln!("Device '{}' not found, using default", input_device_name);
-                        host.default_input_device()
-                    })
-            };
-            let device = match device {
-                Some(d) => d,
-                None => {
-                    eprintln!("No audio input device found");
-                    return;
-                }
-            };
-            let supported = match device.default_input_config() {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!("No input config: {e}");
-                    return;
-                }
-            };
-
-            *sr.lock().unwrap() = supported.sample_rate().0;
-            let channels = supported.channels() as usize;
-            let fmt = supported.sample_format();
-            let config: cpal::StreamConfig = supported.into();
-
-            let _stream = match fmt {
-                cpal::SampleFormat::F32 => {
-                    let buf = buf.clone();
-                    device
-                        .build_input_stream(
-                            &config,
-                            move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                                let mut b = buf.lock().unwrap();
-                                if channels > 1 {
-                                    for chunk in data.chunks(channels) {
-                                        b.push(chunk.iter().sum::<f32>() / channels as f32);
-                                    }
-                                } else {
-                                    b.extend_from_slice(data);
-                                }
-                            },
-                            |e| eprintln!("Audio error: {e}"),
-                            None,
-                        )
-                        .expect("Failed to build input stream")
-                }
-                cpal::SampleFormat::I16 => {
-                    let buf = buf.clone();
-                    device
-                        .build_input_stream(
-                            &config,
-                            move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                                let mut b = buf.lock().unwrap();
-                                if channels > 1 {
-                                    for chunk in data.chunks(channels) {
-                                        let sum: f32 =
-                                            chunk.iter().map(|&s| s as f32 / 32768.0).sum();
-                                        b.push(sum / channels as f32);
-                                    }
-                                } else {
-                                    for &s in data {
-                                        b.push(s as f32 / 32768.0);
-                                    }
-                                }
-                            },
-                            |e| eprintln!("Audio error: {e}"),
-                            None,
-                        )
-                        .expect("Failed to build input stream")
-                }
-                fmt => panic!("Unsupported sample format: {fmt:?}"),
-            };
-
-            _stream.play().expect("Failed to start audio stream");
-
+            let host = select_host(&host_name);
+            // The Windows build has no console, so problems are shown on the
+            // overlay instead of dying silently
+            let mut shown_error = false;
             while run.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                let health = Arc::new(StreamHealth::default());
+                match open_input_stream(
+                    &host,
+                    &input_device_name,
+                    force_sample_rate,
+                    &buf,
+                    &sr,
+                    &health,
+                ) {
+                    Ok((stream, name)) => {
+                        if std::mem::take(&mut shown_error) {
+                            let mut t = transcript.lock().unwrap_or_else(recover_poison);
+                            if t.starts_with('⚠') {
+                                t.clear();
+                            }
+                        }
+                        let lost =
+                            watch_input_stream(&host, &input_device_name, &name, &health, &run);
+                        drop(stream);
+                        if let Some(reason) = lost {
+                            eprintln!("Audio input '{name}' {reason}, reconnecting");
+                        }
+                        if lost.is_some_and(|reason| reason != DEFAULT_CHANGED) {
+                            *transcript.lock().unwrap_or_else(recover_poison) =
+                                "⚠ Audio input lost, reconnecting…".to_string();
+                            shown_error = true;
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("{e}, retrying");
+                        *transcript.lock().unwrap_or_else(recover_poison) = format!("⚠ {e}");
+                        shown_error = true;
+                        let retry_at = Instant::now() + Duration::from_millis(RECONNECT_DELAY_MS);
+                        while run.load(Ordering::Relaxed) && Instant::now() < retry_at {
+                            thread::sleep(Duration::from_millis(100));
+                        }
+                    }
+                }
             }
         });
     }
@@ -308,30 +1997,46 @@ pub fn start_audio_and_transcription(
         let sr = sample_rate;
         let run = running;
         thread::spawn(move || {
-            let client = reqwest::blocking::Client::builder()
-                .timeout(Duration::from_secs(30))
-                .build()
-                .expect("Failed to build HTTP client");
+            let mut clients = HttpClients::new(&settings.lock().unwrap_or_else(recover_poison));
 
             thread::sleep(Duration::from_secs(1));
 
+            let mut poll_interval_ms = POLL_INTERVAL_MS;
             let mut speaking = false;
             let mut phrase: Vec<f32> = Vec::new();
+            // Stream position in samples; unlike wall-clock time it isn't skewed
+            // by the blocking API calls while audio keeps buffering
+            let mut stream_pos: usize = 0;
+            // Samples since the session started, muted and warm-up time
+            // included, so subtitle cues line up with a recording of it
+            let mut session_pos: usize = 0;
+            let mut phrase_start_pos: usize = 0;
+            let mut last_phrase_end: Option<usize> = None;
+            // Last phrase, the position it ended at and whether it produced
+            // text, for merging breath-split fragments
+            let mut last_fragment: Option<(Vec<f32>, usize, bool)> = None;
             let mut silence_count: usize = 0;
-            let mut translation_history: VecDeque<(String, String)> = VecDeque::new();
-            let mut log_file: Option<std::fs::File> = None;
+            let mut session = SessionState {
+                session_text: status.session_text.clone(),
+                ..Default::default()
+            };
             let mut was_session_active = false;
+            let mut zero_samples: usize = 0;
+            // Last retro_buffer_secs of input, kept whether or not a session is running
+            let mut retro: VecDeque<f32> = VecDeque::new();
+            let mut agc = Agc::default();
+            let mut grace_until: Option<Instant> = None;
 
             while run.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(50));
+                thread::sleep(Duration::from_millis(poll_interval_ms));
 
-                let rate = *sr.lock().unwrap();
+                let rate = *sr.lock().unwrap_or_else(recover_poison);
                 if rate == 0 {
                     continue;
                 }
 
-                let new_samples: Vec<f32> = {
-                    let mut b = buf.lock().unwrap();
+                let mut new_samples: Vec<f32> = {
+                    let mut b = buf.lock().unwrap_or_else(recover_poison);
                     std::mem::take(&mut *b)
                 };
 
@@ -339,48 +2044,138 @@ pub fn start_audio_and_transcription(
                     continue;
                 }
 
+                let current = settings.lock().unwrap_or_else(recover_poison).clone();
+                poll_interval_ms = current.poll_interval_ms();
+                let silence_chunks_to_end = current.silence_chunks_to_end();
+
+                retro.extend(&new_samples);
+                let retro_len = rate as usize * current.retro_buffer_secs as usize;
+                if retro.len() > retro_len {
+                    retro.drain(..retro.len() - retro_len);
+                }
+                if status.save_retro.swap(false, Ordering::Relaxed) {
+                    save_retro_buffer(&mut retro, rate);
+                }
+
+                if current.agc_enabled {
+                    agc.process(&mut new_samples, rate, &current);
+                }
+                // Measured even outside a session so the threshold can be tuned
+                let energy = rms(&new_samples);
+                status.input_level.store(energy.to_bits(), Ordering::Relaxed);
+
+                // Hardware/OS mute can't be queried portably, so infer it from
+                // a sustained run of exact-zero samples
+                if new_samples.iter().all(|&s| s == 0.0) {
+                    zero_samples += new_samples.len();
+                } else {
+                    zero_samples = 0;
+                }
+                let is_muted =
+                    current.detect_mute && zero_samples >= rate as usize * MUTE_DETECT_SECS;
+                status.muted.store(is_muted, Ordering::Relaxed);
+
                 // Session state transitions
                 let is_active = session_active.load(Ordering::Relaxed);
                 if is_active && !was_session_active {
-                    let dir = crate::settings::sessions_dir();
-                    let filename = format!(
-                        "session_{}.txt",
+                    session.log_prefix = format!(
+                        "session_{}",
                         chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")
                     );
-                    match std::fs::File::create(dir.join(&filename)) {
-                        Ok(f) => log_file = Some(f),
-                        Err(e) => eprintln!("Failed to create session log: {e}"),
-                    }
+                    session.log_part = 1;
+                    session.session_text.lock().unwrap_or_else(recover_poison).clear();
+                    // Context from an earlier session would leak into unrelated phrases
+                    session.history.clear();
+                    session.open_log();
+                    session.open_subtitles(current.subtitle_format);
+                    session_pos = 0;
+                    grace_until = Some(
+                        Instant::now() + Duration::from_secs(current.startup_grace_secs as u64),
+                    );
+                    crate::settings::prune_session_files(current.max_session_files);
                     was_session_active = true;
                 } else if !is_active && was_session_active {
-                    log_file = None;
-                    *transcript.lock().unwrap() = String::new();
+                    flush_sentence(&clients, &status, &transcript, &current, &mut session);
+                    session.flush_pending_log(&current);
+                    session.log_file = None;
+                    session.subtitle_file = None;
+                    grace_until = None;
+                    status.warming_up.store(false, Ordering::Relaxed);
+                    session.log_opened = None;
+                    last_fragment = None;
+                    last_phrase_end = None;
+                    status.clear_captions();
+                    *transcript.lock().unwrap_or_else(recover_poison) = String::new();
                     phrase.clear();
                     speaking = false;
+                    status.speaking.store(false, Ordering::Relaxed);
                     silence_count = 0;
                     was_session_active = false;
                 }
 
+                session.log_comparisons();
+
                 if !is_active {
                     continue;
                 }
+                session_pos += new_samples.len();
 
-                let (threshold, api_url, api_key, language, chat_api_url, chat_api_key, chat_model, target_language, display_mode) = {
-                    let s = settings.lock().unwrap();
-                    (
-                        s.silence_threshold,
-                        s.api_url.clone(),
-                        s.api_key.clone(),
-                        s.language.clone(),
-                        s.chat_api_url.clone(),
-                        s.chat_api_key.clone(),
-                        s.chat_model.clone(),
-                        s.target_language.clone(),
-                        s.display_mode.clone(),
-                    )
-                };
+                // Long events: continue the log in a new part file
+                if current.session_rotate_mins > 0
+                    && session.log_opened.is_some_and(|t| {
+                        t.elapsed().as_secs() >= current.session_rotate_mins as u64 * 60
+                    })
+                {
+                    session.flush_pending_log(&current);
+                    session.rotate_log();
+                }
 
-                let energy = rms(&new_samples);
+                // Periodic mode flushes from here so slow storage is only hit
+                // every few seconds; OnClose relies on the writer flushing on drop
+                if current.log_flush_mode == LogFlushMode::Periodic
+                    && let Some(file) = &mut session.log_file
+                    && session.last_flush.is_none_or(|t| t.elapsed().as_secs() >= LOG_FLUSH_SECS)
+                {
+                    use std::io::Write;
+                    let _ = file.flush();
+                    session.last_flush = Some(Instant::now());
+                }
+
+                let hidden =
+                    current.capture_only_when_visible && status.window_hidden.load(Ordering::Relaxed);
+                if is_muted || hidden {
+                    // Drop any partial phrase so nothing is emitted while muted
+                    // or while the overlay is out of sight
+                    phrase.clear();
+                    speaking = false;
+                    status.speaking.store(false, Ordering::Relaxed);
+                    silence_count = 0;
+                    continue;
+                }
+
+                // Startup grace: keep listening but drop whatever is said while
+                // the presenter is still setting up
+                let warming_up = grace_until.is_some_and(|t| Instant::now() < t);
+                status.warming_up.store(warming_up, Ordering::Relaxed);
+                if warming_up {
+                    phrase.clear();
+                    speaking = false;
+                    status.speaking.store(false, Ordering::Relaxed);
+                    silence_count = 0;
+                    continue;
+                }
+
+                let threshold = current.silence_threshold;
+                clients.refresh(&current);
+
+                let sentence_timeout = Duration::from_millis(current.sentence_timeout_ms as u64);
+                if session.sentence_started.is_some_and(|t| t.elapsed() >= sentence_timeout) {
+                    status.processing.store(true, Ordering::Relaxed);
+                    flush_sentence(&clients, &status, &transcript, &current, &mut session);
+                    status.processing.store(false, Ordering::Relaxed);
+                }
+
+                stream_pos += new_samples.len();
                 let is_voice = energy > threshold;
 
                 if speaking {
@@ -393,42 +2188,302 @@ pub fn start_audio_and_transcription(
                     }
 
                     // End of phrase: sustained silence after speech
-                    let phrase_too_long = phrase.len() > rate as usize * MAX_PHRASE_SECS;
-                    if silence_count >= SILENCE_CHUNKS_TO_END || phrase_too_long {
+                    let phrase_too_long =
+                        phrase.len() > rate as usize * current.max_phrase_secs.max(1) as usize;
+                    if silence_count >= silence_chunks_to_end || phrase_too_long {
                         // Trim trailing silence
                         let trim_samples = silence_count * new_samples.len();
                         let end = phrase.len().saturating_sub(trim_samples);
-                        if end > rate as usize / 2 {
-                            send_transcription(
-                                &client,
-                                &phrase[..end],
+                        // A forced cut usually lands mid-speech: carry the tail
+                        // into the next phrase so a split word is heard whole in
+                        // one of the clips (the duplicate text is stripped later)
+                        let carry = if phrase_too_long && silence_count < silence_chunks_to_end {
+                            (rate as usize * current.overlap_carry_ms as usize / 1000).min(end)
+                        } else {
+                            0
+                        };
+                        let mut transcribed = false;
+                        if end as f32 > rate as f32 * current.min_phrase_secs() {
+                            // A phrase starting right after a short one is likely the
+                            // rest of the same sentence: re-transcribe them together
+                            let merge_with = last_fragment.take().filter(|(prev, ended, _)| {
+                                merges_with_fragment(prev, *ended, phrase_start_pos, rate, &current)
+                            });
+                            let audio = match merge_with {
+                                Some((mut prev, _, fragment_shown)) => {
+                                    prev.extend_from_slice(&phrase[..end]);
+                                    // A fragment already on screen and in the log
+                                    // only gets the new tail added after it
+                                    session.replaces_previous = fragment_shown;
+                                    prev
+                                }
+                                None => phrase[..end].to_vec(),
+                            };
+                            session.gap_secs = last_phrase_end.map(|ended| {
+                                phrase_start_pos.saturating_sub(ended) as f32 / rate as f32
+                            });
+                            let span_end = session_pos.saturating_sub(trim_samples);
+                            let new_len = if session.replaces_previous { end } else { audio.len() };
+                            let span_start = span_end.saturating_sub(new_len);
+                            session.phrase_span = Some((
+                                span_start as f32 / rate as f32,
+                                span_end as f32 / rate as f32,
+                            ));
+                            status.processing.store(true, Ordering::Relaxed);
+                            transcribed = send_transcription(
+                                &clients,
+                                &status,
+                                &audio,
                                 rate,
                                 &transcript,
-                                &api_url,
-                                &api_key,
-                                &language,
-                                &chat_api_url,
-                                &chat_api_key,
-                                &chat_model,
-                                &target_language,
-                                &display_mode,
-                                &mut translation_history,
-                                &mut log_file,
+                                &current,
+                                &mut session,
                             );
+                            status.processing.store(false, Ordering::Relaxed);
+                            let ended = stream_pos - trim_samples;
+                            last_phrase_end = Some(ended);
+                            last_fragment = Some((audio, ended, transcribed));
+                        }
+                        if carry > 0 {
+                            phrase.drain(..end - carry);
+                            phrase.truncate(carry);
+                            // Only trim against text this phrase actually produced,
+                            // not an older phrase's after a failed or dropped send
+                            session.overlaps_previous = transcribed;
+                        } else {
+                            phrase.clear();
+                            speaking = false;
                         }
-                        phrase.clear();
-                        speaking = false;
                         silence_count = 0;
                     }
                 } else if is_voice {
                     // Speech started
                     speaking = true;
+                    phrase_start_pos = stream_pos - new_samples.len();
                     silence_count = 0;
                     phrase.clear();
                     phrase.extend_from_slice(&new_samples);
                 }
                 // If silent and not speaking, discard samples
+                status.speaking.store(speaking, Ordering::Relaxed);
             }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    fn merge_settings() -> Settings {
+        Settings {
+            merge_fragments: true,
+            merge_gap_ms: 400,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn close_short_phrases_merge() {
+        let settings = merge_settings();
+        // 1s fragment ending at 1s, next phrase 0.2s later
+        let fragment = vec![0.1; RATE as usize];
+        let next_start = RATE as usize + RATE as usize / 5;
+        assert!(merges_with_fragment(&fragment, RATE as usize, next_start, RATE, &settings));
+        // Exactly at the gap limit still merges
+        let at_limit = RATE as usize + RATE as usize * 400 / 1000;
+        assert!(merges_with_fragment(&fragment, RATE as usize, at_limit, RATE, &settings));
+    }
+
+    #[test]
+    fn distant_or_long_phrases_stay_separate() {
+        let settings = merge_settings();
+        let fragment = vec![0.1; RATE as usize];
+        // 1s pause is longer than the 400 ms gap
+        let next_start = 2 * RATE as usize;
+        assert!(!merges_with_fragment(&fragment, RATE as usize, next_start, RATE, &settings));
+        // A 3s phrase isn't a fragment
+        let long = vec![0.1; 3 * RATE as usize];
+        let end = long.len();
+        assert!(!merges_with_fragment(&long, end, end + 100, RATE, &settings));
+    }
+
+    #[test]
+    fn merging_off_keeps_phrases_separate() {
+        let settings = Settings {
+            merge_fragments: false,
+            ..merge_settings()
+        };
+        let fragment = vec![0.1; RATE as usize];
+        assert!(!merges_with_fragment(&fragment, RATE as usize, RATE as usize, RATE, &settings));
+    }
+
+    fn peak(samples: &[f32]) -> f32 {
+        samples.iter().fold(0.0f32, |m, s| m.max(s.abs()))
+    }
+
+    #[test]
+    fn normalize_raises_quiet_phrase_to_target() {
+        let target = Settings::default().normalize_target;
+        let quiet: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.05).sin() * 0.1).collect();
+        let normalized = normalize_peak(&quiet, target);
+        assert!((peak(&normalized) - target).abs() < 1e-4, "{}", peak(&normalized));
+    }
+
+    #[test]
+    fn normalize_does_not_clip_loud_phrase() {
+        let loud: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.05).sin() * 1.5).collect();
+        let normalized = normalize_peak(&loud, 0.9);
+        assert!(peak(&normalized) <= 1.0);
+        assert!((peak(&normalized) - 0.9).abs() < 1e-4, "{}", peak(&normalized));
+        // Relative levels are kept, nothing is flattened at full scale
+        assert!(!normalized.iter().any(|s| s.abs() >= 1.0));
+    }
+
+    #[test]
+    fn normalize_silence_stays_finite() {
+        let normalized = normalize_peak(&[0.0; 512], 0.9);
+        assert!(normalized.iter().all(|s| s.is_finite() && *s == 0.0));
+        assert!(normalize_peak(&[], 0.9).is_empty());
+    }
+
+    fn tone(freq: f32, rate: u32, secs: f32) -> Vec<f32> {
+        let n = (rate as f32 * secs) as usize;
+        (0..n)
+            .map(|i| (2.0 * std::f32::consts::PI * freq * i as f32 / rate as f32).sin() * 0.5)
+            .collect()
+    }
+
+    // Peak over the middle, away from edge effects
+    fn middle_peak(samples: &[f32]) -> f32 {
+        peak(&samples[samples.len() / 4..samples.len() * 3 / 4])
+    }
+
+    #[test]
+    fn resample_keeps_16k_input() {
+        let input = tone(440.0, 16_000, 0.1);
+        for quality in [ResampleQuality::Linear, ResampleQuality::Sinc] {
+            assert_eq!(resample_to_16k(&input, 16_000, quality), input);
+        }
+    }
+
+    #[test]
+    fn resample_output_length() {
+        for rate in [8_000, 44_100, 48_000] {
+            let input = vec![0.25; rate as usize];
+            for quality in [ResampleQuality::Linear, ResampleQuality::Sinc] {
+                let out = resample_to_16k(&input, rate, quality);
+                assert_eq!(out.len(), 16_000, "{rate}");
+                // A constant level comes through unchanged
+                assert!(out.iter().all(|s| (s - 0.25).abs() < 1e-3), "{rate}");
+            }
+        }
+    }
+
+    #[test]
+    fn sinc_keeps_speech_band() {
+        for rate in [44_100, 48_000] {
+            let out = resample_to_16k(&tone(1000.0, rate, 0.5), rate, ResampleQuality::Sinc);
+            assert!((middle_peak(&out) - 0.5).abs() < 0.01, "{rate}: {}", middle_peak(&out));
+        }
+    }
+
+    #[test]
+    fn sinc_filters_what_would_alias() {
+        // 12 kHz can't be represented at 16 kHz and would fold back to 4 kHz
+        let input = tone(12_000.0, 48_000, 0.5);
+        let sinc = resample_to_16k(&input, 48_000, ResampleQuality::Sinc);
+        let linear = resample_to_16k(&input, 48_000, ResampleQuality::Linear);
+        assert!(middle_peak(&sinc) < 0.01, "{}", middle_peak(&sinc));
+        assert!(middle_peak(&sinc) < middle_peak(&linear));
+    }
+
+    #[test]
+    fn sinc_runs_faster_than_real_time() {
+        // 30 s phrase; debug builds are far slower than release, so this
+        // only catches a return to per-sample tap computation
+        for rate in [44_100, 48_000] {
+            let input = tone(440.0, rate, 30.0);
+            let started = Instant::now();
+            let out = resample_to_16k(&input, rate, ResampleQuality::Sinc);
+            let elapsed = started.elapsed();
+            assert_eq!(out.len(), 16_000 * 30);
+            assert!(elapsed < Duration::from_secs(5), "{rate}: {elapsed:?}");
+        }
+    }
+
+    #[test]
+    fn upload_filename_matches_codec() {
+        let named = |name: &str| {
+            upload_filename(&Settings {
+                upload_filename: name.to_string(),
+                ..Default::default()
+            })
+        };
+        assert_eq!(named(""), "audio.wav");
+        assert_eq!(named("  "), "audio.wav");
+        assert_eq!(named("clip"), "clip.wav");
+        assert_eq!(named("clip.wav"), "clip.wav");
+        assert_eq!(named("clip.flac"), "clip.wav");
+        assert_eq!(named("talk.part1.mp3"), "talk.part1.wav");
+    }
+
+    #[test]
+    fn merged_phrase_adds_one_entry_after_its_fragment() {
+        use std::io::Write;
+        let settings = Settings::default();
+        let name = format!("livecaptran_merge_{}.srt", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        let file = std::io::BufWriter::new(std::fs::File::create(&path).unwrap());
+        let mut session = SessionState {
+            subtitle_file: Some((file, SubtitleFormat::Srt)),
+            ..Default::default()
+        };
+
+        // The short fragment is shown and logged as usual
+        session.phrase_span = Some((0.0, 1.0));
+        let fragment = new_source_text(&mut session, &settings, "we measured it", false).unwrap();
+        session.last_text = fragment.clone();
+        session.log_phrase(&settings, &fragment, None, None);
+
+        // Re-transcribed together with the next phrase, only the tail is new
+        session.phrase_span = Some((1.2, 2.0));
+        let merged =
+            new_source_text(&mut session, &settings, " We measured it twice. ", true).unwrap();
+        assert_eq!(merged, "twice.");
+        session.log_phrase(&settings, &merged, None, None);
+
+        let log = session.session_text.lock().unwrap().join("\n");
+        assert_eq!(log.matches("measured").count(), 1, "{log}");
+        assert_eq!(log.matches("twice").count(), 1, "{log}");
+
+        let (mut file, _) = session.subtitle_file.take().unwrap();
+        file.flush().unwrap();
+        drop(file);
+        let cues = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(session.cue_count, 2);
+        assert_eq!(cues.matches("measured").count(), 1, "{cues}");
+        assert!(cues.contains("00:00:01,200 --> 00:00:02,000\ntwice."), "{cues}");
+    }
+
+    #[test]
+    fn unmerged_phrase_keeps_repeat_handling() {
+        let settings = Settings {
+            repeat_handling: RepeatHandling::Skip,
+            ..Default::default()
+        };
+        let mut session = SessionState {
+            last_text: "we measured it".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(new_source_text(&mut session, &settings, "we measured it", false), None);
+        // A merge never counts as an echo of the fragment it extends
+        assert_eq!(
+            new_source_text(&mut session, &settings, "we measured it again", true).as_deref(),
+            Some("again")
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::net::IpAddr;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use std::thread;
+
+use crate::audio::{recover_poison, WorkerStatus};
+use crate::settings::{DisplayMode, Settings, SOURCE_LANGUAGES, TARGET_LANGUAGES};
+
+// Local HTTP control API for show-control tools (Stream Deck, Companion, ...)
+//
+//   GET  /caption          -> {"text": "..."}
+//   GET  /status           -> session state, language pair and display mode
+//   POST /session/start    -> start a session
+//   POST /session/stop     -> stop the session
+//   POST /session/toggle   -> toggle the session
+//   POST /language         -> body {"language": "ko", "target_language": "en"},
+//                             either field optional, "" target disables translation
+//
+// There is no authentication, so requests a web page could make are refused:
+// anything carrying an Origin header, a Host that isn't localhost or an IP
+// address (DNS rebinding), and a POST that isn't application/json, which a
+// cross-site form or no-cors fetch can't send.
+pub fn start_control_api(
+    bind: String,
+    transcript: Arc<Mutex<String>>,
+    settings: Arc<Mutex<Settings>>,
+    session_active: Arc<AtomicBool>,
+    status: Arc<WorkerStatus>,
+) {
+    thread::spawn(move || {
+        let server = match tiny_http::Server::http(&bind) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Failed to start control API on {bind}: {e}");
+                return;
+            }
+        };
+        for mut request in server.incoming_requests() {
+            let method = request.method().clone();
+            let path = request.url().split('?').next().unwrap_or("").to_string();
+            let (code, body) = match rejection(&request) {
+                Some((code, error)) => (code, serde_json::json!({ "error": error })),
+                None => match (method, path.as_str()) {
+                    (tiny_http::Method::Get, "/caption") => {
+                        let text = transcript.lock().unwrap_or_else(recover_poison).clone();
+                        (200, serde_json::json!({ "text": text }))
+                    }
+                    (tiny_http::Method::Get, "/status") => {
+                        (200, status_json(&settings, &session_active))
+                    }
+                    (tiny_http::Method::Post, "/session/start") => {
+                        status.set_session_active(&session_active, &transcript, true);
+                        (200, status_json(&settings, &session_active))
+                    }
+                    (tiny_http::Method::Post, "/session/stop") => {
+                        status.set_session_active(&session_active, &transcript, false);
+                        (200, status_json(&settings, &session_active))
+                    }
+                    (tiny_http::Method::Post, "/session/toggle") => {
+                        let active = !session_active.load(Ordering::Relaxed);
+                        status.set_session_active(&session_active, &transcript, active);
+                        (200, status_json(&settings, &session_active))
+                    }
+                    (tiny_http::Method::Post, "/language") => {
+                        let mut raw = String::new();
+                        let _ = request.as_reader().read_to_string(&mut raw);
+                        match set_language(&settings, &raw) {
+                            Ok(()) => (200, status_json(&settings, &session_active)),
+                            Err(e) => (400, serde_json::json!({ "error": e })),
+                        }
+                    }
+                    _ => (404, serde_json::json!({ "error": "not found" })),
+                },
+            };
+            let response = tiny_http::Response::from_string(body.to_string())
+                .with_status_code(code)
+                .with_header(
+                    tiny_http::Header::from_bytes("Content-Type", "application/json").unwrap(),
+                );
+            let _ = request.respond(response);
+        }
+    });
+}
+
+// Status code and reason for requests that didn't come from a local tool
+fn rejection(request: &tiny_http::Request) -> Option<(u16, &'static str)> {
+    let header = |name: &str| {
+        request
+            .headers()
+            .iter()
+            .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case(name))
+            .map(|h| h.value.as_str())
+    };
+    if header("Origin").is_some() {
+        return Some((403, "cross-origin requests are not allowed"));
+    }
+    if !header("Host").is_some_and(is_local_host) {
+        return Some((403, "Host must be localhost or an IP address"));
+    }
+    if *request.method() == tiny_http::Method::Post
+        && !header("Content-Type").is_some_and(is_json_content_type)
+    {
+        return Some((415, "Content-Type must be application/json"));
+    }
+    None
+}
+
+// Host header value with any port removed must be localhost or an IP literal
+fn is_local_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host.split(':').next().unwrap_or(""),
+    };
+    name.eq_ignore_ascii_case("localhost") || name.parse::<IpAddr>().is_ok()
+}
+
+fn is_json_content_type(value: &str) -> bool {
+    let media = value.split(';').next().unwrap_or("").trim();
+    media.eq_ignore_ascii_case("application/json")
+}
+
+fn status_json(
+    settings: &Arc<Mutex<Settings>>,
+    session_active: &AtomicBool,
+) -> serde_json::Value {
+    let s = settings.lock().unwrap_or_else(recover_poison);
+    serde_json::json!({
+        "session_active": session_active.load(Ordering::Relaxed),
+        "language": s.language,
+        "target_language": s.target_language,
+        "display_mode": match s.display_mode {
+            DisplayMode::TranslationOnly => "translation_only",
+            DisplayMode::Both => "both",
+        },
+    })
+}
+
+fn set_language(settings: &Arc<Mutex<Settings>>, raw: &str) -> Result<(), String> {
+    let json: serde_json::Value =
+        serde_json::from_str(raw).map_err(|e| format!("invalid JSON: {e}"))?;
+    let language = json["language"].as_str();
+    let target = json["target_language"].as_str();
+    if let Some(code) = language
+        && !SOURCE_LANGUAGES.iter().any(|(c, _)| *c == code)
+    {
+        return Err(format!("unknown source language '{code}'"));
+    }
+    if let Some(code) = target
+        && !code.is_empty()
+        && !TARGET_LANGUAGES.iter().any(|(c, _)| *c == code)
+    {
+        return Err(format!("unknown target language '{code}'"));
+    }
+
+    let mut s = settings.lock().unwrap_or_else(recover_poison);
+    if let Some(code) = language {
+        s.language = code.to_string();
+    }
+    if let Some(code) = target {
+        s.target_language = code.to_string();
+    }
+    s.save();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_check_allows_only_local_names_and_ip_literals() {
+        assert!(is_local_host("127.0.0.1:8787"));
+        assert!(is_local_host("localhost:8787"));
+        assert!(is_local_host("192.168.1.20:8787"));
+        assert!(is_local_host("[::1]:8787"));
+        assert!(!is_local_host("attacker.example:8787"));
+        assert!(!is_local_host(""));
+    }
+
+    #[test]
+    fn content_type_must_be_json() {
+        assert!(is_json_content_type("application/json"));
+        assert!(is_json_content_type("Application/JSON; charset=utf-8"));
+        assert!(!is_json_content_type("text/plain"));
+        assert!(!is_json_content_type("application/x-www-form-urlencoded"));
+    }
+}
@@ -0,0 +1,586 @@
+// Settings UI translations, keyed by the English string. Anything missing
+// from a table falls back to English.
+
+pub const UI_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("ko", "한국어"),
+    ("es", "Español"),
+];
+
+pub fn tr(ui_language: &str, english: &'static str) -> &'static str {
+    let table = match ui_language {
+        "ko" => KO,
+        "es" => ES,
+        _ => return english,
+    };
+    table
+        .iter()
+        .find(|(key, _)| *key == english)
+        .map(|(_, text)| *text)
+        .unwrap_or(english)
+}
+
+const KO: &[(&str, &str)] = &[
+    ("LiveCapTran Settings", "LiveCapTran 설정"),
+    ("UI Language:", "UI 언어:"),
+    ("Transcribe API URL:", "음성 인식 API URL:"),
+    ("Transcribe API Key:", "음성 인식 API 키:"),
+    ("Transcribe Timeout:", "음성 인식 제한 시간:"),
+    ("Upload Filename:", "업로드 파일 이름:"),
+    ("Language:", "언어:"),
+    ("Candidate Languages:", "후보 언어:"),
+    ("Silence Threshold:", "무음 임계값:"),
+    ("Font Size:", "글꼴 크기:"),
+    ("Min Font Size:", "최소 글꼴 크기:"),
+    ("Overflow:", "넘침 처리:"),
+    ("Scroll to newest text instead of shrinking further", "더 줄이지 않고 최신 텍스트로 스크롤"),
+    ("Font Smoothing:", "글꼴 크기 전환:"),
+    ("Ease size changes", "크기 변화를 부드럽게"),
+    ("Chat API URL:", "채팅 API URL:"),
+    ("Chat API Key:", "채팅 API 키:"),
+    ("Chat Model:", "채팅 모델:"),
+    ("Chat Timeout:", "채팅 제한 시간:"),
+    ("Cleanup:", "정리:"),
+    ("Remove fillers and fix punctuation (adds latency)", "군말 제거 및 문장부호 교정 (지연 증가)"),
+    ("Cleanup Prompt:", "정리 프롬프트:"),
+    ("Translate To:", "번역 언어:"),
+    ("None", "없음"),
+    ("Casing:", "대소문자:"),
+    ("As translated", "번역 그대로"),
+    ("Sentence case", "문장 첫 글자만 대문자"),
+    ("Display:", "표시:"),
+    ("Both", "원문과 번역"),
+    ("Translation only", "번역만"),
+    ("Display Mode Key:", "표시 모드 단축키:"),
+    ("Cycles the display mode while the overlay has focus. Leave empty to disable.", "오버레이에 포커스가 있을 때 표시 모드를 전환합니다. 비워 두면 사용하지 않습니다."),
+    ("Numeric Phrases:", "숫자 위주 구절:"),
+    ("Translate", "번역"),
+    ("Keep verbatim", "그대로 유지"),
+    ("Show source", "원문 표시"),
+    ("Numeric Ratio:", "숫자 비율:"),
+    ("Opacity:", "불투명도:"),
+    ("Audio Host:", "오디오 호스트:"),
+    ("Default", "기본값"),
+    ("Takes effect after restart", "다시 시작한 후 적용됩니다"),
+    ("Input Device:", "입력 장치:"),
+    ("Detect Mute:", "음소거 감지:"),
+    ("Treat silent (all-zero) input as muted", "완전한 무음 입력을 음소거로 간주"),
+    ("Clock:", "시계:"),
+    ("Show", "표시"),
+    ("Wall clock", "현재 시각"),
+    ("Session elapsed", "세션 경과 시간"),
+    ("Clock Format:", "시계 형식:"),
+    ("Clock Corner:", "시계 위치:"),
+    ("Top left", "왼쪽 위"),
+    ("Top right", "오른쪽 위"),
+    ("Bottom left", "왼쪽 아래"),
+    ("Bottom right", "오른쪽 아래"),
+    ("Noise Filter:", "잡음 필터:"),
+    ("Discard results with no words (punctuation, symbols, emoji)", "단어가 없는 결과 버리기 (문장부호, 기호, 이모지)"),
+    ("Extra Noise Chars:", "추가 잡음 문자:"),
+    ("Status Bar:", "상태 표시줄:"),
+    ("Top", "위"),
+    ("Bottom", "아래"),
+    ("Processing Indicator:", "처리 표시:"),
+    ("Show a spinner while a phrase is processed", "구절 처리 중 스피너 표시"),
+    ("Upload Progress:", "업로드 진행률:"),
+    ("Show a progress bar while audio uploads", "오디오 업로드 중 진행 막대 표시"),
+    ("Auto Start:", "자동 시작:"),
+    ("Start a session on launch", "실행 시 세션 시작"),
+    ("Cut Overlap:", "분할 겹침:"),
+    ("Audio carried into the next phrase when a long phrase is cut", "긴 구절을 자를 때 다음 구절로 넘기는 오디오"),
+    ("Merge Fragments:", "조각 병합:"),
+    ("Within", "간격"),
+    ("Re-transcribe a short phrase together with one that follows it closely", "짧은 구절을 바로 뒤따르는 구절과 함께 다시 인식"),
+    ("Repeated Text:", "반복된 텍스트:"),
+    ("Keep", "유지"),
+    ("Skip", "건너뛰기"),
+    ("New part only", "새 부분만"),
+    ("Repeat Similarity:", "반복 유사도:"),
+    ("Debug Log:", "디버그 로그:"),
+    ("Write debug.log", "debug.log 기록"),
+    ("Include bodies", "본문 포함"),
+    ("Low Power:", "저전력:"),
+    ("Poll and repaint less often (adds latency)", "폴링과 화면 갱신을 줄임 (지연 증가)"),
+    ("Control API:", "제어 API:"),
+    ("Enable", "사용"),
+    ("All Workspaces:", "모든 작업 공간:"),
+    ("Show on every virtual desktop", "모든 가상 데스크톱에 표시"),
+    ("Windows and Linux (X11) only. On Windows this also hides the overlay from the taskbar.", "Windows와 Linux(X11)에서만 지원됩니다. Windows에서는 작업 표시줄에서도 숨겨집니다."),
+    ("Turn Markers:", "발화 구분:"),
+    ("Mark gaps over", "다음보다 긴 간격 표시"),
+    ("Writes a separator to the session log before a phrase that follows a long pause", "긴 멈춤 뒤의 구절 앞에 세션 로그 구분선을 기록"),
+    ("Caption Queue:", "자막 대기열:"),
+    ("Show each for", "각 자막 표시 시간"),
+    ("Holds captions that finish while the previous one is still on screen instead of replacing it", "이전 자막이 표시 중일 때 끝난 자막을 바꾸지 않고 대기시킴"),
+    ("Max Queued:", "최대 대기 수:"),
+    ("Oldest pending captions are dropped beyond this", "이 수를 넘으면 가장 오래된 대기 자막을 버림"),
+    ("Display", "표시"),
+    ("Recording", "녹음 중"),
+    ("Idle", "대기"),
+    ("Connection Pool:", "연결 풀:"),
+    ("idle", "유휴"),
+    ("Idle connections kept open per API host, and for how long. 0 opens a new connection for every request.", "API 호스트별로 열어 둘 유휴 연결 수와 유지 시간. 0이면 요청마다 새로 연결합니다."),
+    ("HTTP/2:", "HTTP/2:"),
+    ("Use HTTP/2 when the server supports it", "서버가 지원하면 HTTP/2 사용"),
+    ("Lower Third:", "하단 자막 띠:"),
+    ("Bottom band", "하단 띠"),
+    ("Draw the background and caption only in a fixed-height band along the bottom", "배경과 자막을 아래쪽의 고정 높이 띠에만 표시"),
+    ("Physical Size:", "물리적 크기:"),
+    ("Keep size across monitors with different scaling", "배율이 다른 모니터에서도 크기 유지"),
+    ("Sample Rate:", "샘플레이트:"),
+    ("Falls back to the device default if unsupported. Takes effect after restart", "지원되지 않으면 장치 기본값을 사용합니다. 다시 시작한 후 적용됩니다"),
+    ("Log Flush:", "로그 저장:"),
+    ("Every line", "줄마다"),
+    ("Every few seconds", "몇 초마다"),
+    ("On session end", "세션 종료 시"),
+    ("Less frequent flushing helps on network drives, but a crash can lose the unflushed lines", "네트워크 드라이브에서는 덜 자주 저장하는 편이 좋지만, 비정상 종료 시 저장되지 않은 줄은 잃을 수 있습니다"),
+    ("Controls:", "조작 버튼:"),
+    ("Hidden", "숨김"),
+    ("Settings Key:", "설정 단축키:"),
+    ("Close Key:", "닫기 단축키:"),
+    ("Whole Sentences:", "문장 단위 번역:"),
+    ("Translate at sentence end, or after", "문장이 끝나면 번역, 최대 대기"),
+    ("Fragments are shown untranslated until the sentence is complete", "문장이 끝날 때까지 조각은 번역 없이 표시됩니다"),
+    ("Threshold Keys:", "임계값 단축키:"),
+    ("Raise or lower the silence threshold by 20% during a session", "세션 중 무음 임계값을 20%씩 올리거나 내림"),
+    ("Silence Threshold", "무음 임계값"),
+    ("Refine Model:", "검토 모델:"),
+    ("Off", "사용 안 함"),
+    ("A second model reviews each translation against the source. Adds latency and cost", "두 번째 모델이 원문과 대조해 각 번역을 검토합니다. 지연과 비용이 늘어납니다"),
+    ("Learner Mode:", "학습 모드:"),
+    ("Hide translation until revealed, key:", "번역을 가리고 보기 키:"),
+    ("In Both mode the translation is obscured until you hover the caption or press the reveal key", "원문과 번역 모드에서 자막에 마우스를 올리거나 보기 키를 누를 때까지 번역을 가립니다"),
+    ("Startup:", "시작:"),
+    ("Stay transparent for the first frames (avoids a black flash)", "처음 몇 프레임은 투명하게 유지 (검은 화면 깜빡임 방지)"),
+    ("Strip Quotes:", "따옴표 제거:"),
+    ("Outer pair", "바깥 한 쌍"),
+    ("All", "모두"),
+    ("Never", "안 함"),
+    ("Reactive Opacity:", "반응형 불투명도:"),
+    ("Fade to", "다음으로 흐리게"),
+    ("when silent", "(무음일 때)"),
+    ("History:", "이전 문맥:"),
+    ("Include previous translations as context", "이전 번역을 문맥으로 포함"),
+    ("Turn off if the model drifts when fed its own output; only the previous source text is sent then", "모델이 자신의 출력 때문에 흐트러지면 끄세요. 그러면 이전 원문만 보냅니다"),
+    ("Rate limited, retrying in", "요청 한도 초과, 다시 시도까지"),
+    ("Rate Limits:", "요청 한도:"),
+    ("max wait", "최대 대기"),
+    ("Show source instead of waiting", "기다리지 않고 원문 표시"),
+    ("On HTTP 429 the request is retried after the server's Retry-After delay, up to the max wait", "HTTP 429를 받으면 서버의 Retry-After 시간 뒤에 다시 시도합니다 (최대 대기 시간까지)"),
+    ("Emphasis:", "강조:"),
+    ("size", "크기"),
+    ("The key marks the current caption as important until the next phrase", "단축키를 누르면 다음 구절까지 현재 자막을 강조합니다"),
+    ("Split Log:", "로그 분할:"),
+    ("Continue long sessions in session_<time>_partN.txt files. 0 keeps one file", "긴 세션은 session_<시각>_partN.txt 파일로 이어서 기록합니다. 0이면 파일 하나만 사용합니다"),
+    ("Schedule:", "예약:"),
+    ("Run from", "실행 시간"),
+    ("to", "~"),
+    ("Starts the session when the window opens (also when launched inside it) and stops it when it closes. An end before the start crosses midnight", "예약 시간이 시작되면 (그 사이에 실행해도) 세션을 시작하고 끝나면 중지합니다. 종료가 시작보다 이르면 자정을 넘깁니다"),
+    ("Compare API URL:", "비교 API URL:"),
+    ("Compare API Key:", "비교 API 키:"),
+    ("Also sends each phrase to this endpoint and logs both results, labeled, in the session log. Only the primary is displayed", "각 구절을 이 엔드포인트에도 보내고 두 결과를 구분하여 세션 로그에 기록합니다. 화면에는 기본 결과만 표시됩니다"),
+    ("Normalize Audio:", "오디오 정규화:"),
+    ("peak", "피크"),
+    ("Boosts quiet phrases so their peak reaches the target before upload. Voice detection still uses the unprocessed input", "업로드 전에 조용한 구절의 피크가 목표 레벨에 도달하도록 증폭합니다. 음성 감지는 처리되지 않은 입력을 계속 사용합니다"),
+    ("Text Color:", "글자 색:"),
+    ("Chroma Key:", "크로마 키:"),
+    ("Fills the window with a solid key color instead of the translucent background, for keying out in OBS. Pick a text color that contrasts with it", "OBS에서 키잉할 수 있도록 반투명 배경 대신 단색 키 색상으로 창을 채웁니다. 대비되는 글자 색을 선택하세요"),
+    ("Long Words:", "긴 단어:"),
+    ("Break anywhere instead of shrinking the font", "글꼴을 줄이는 대신 아무 곳에서나 줄바꿈"),
+    ("Lets long URLs and compound words wrap mid-token. Any word at the line edge may then be split", "긴 URL이나 합성어를 중간에서 줄바꿈합니다. 줄 끝의 다른 단어도 나뉠 수 있습니다"),
+    ("Show key", "키 보기"),
+    ("Key has leading or trailing spaces", "키 앞뒤에 공백이 있습니다"),
+    ("Key contains spaces or line breaks", "키에 공백이나 줄바꿈이 있습니다"),
+    ("Key is wrapped in quotes", "키가 따옴표로 감싸져 있습니다"),
+    ("Remove the \"Bearer\" prefix; it is added automatically", "\"Bearer\" 접두사를 지우세요. 자동으로 추가됩니다"),
+    ("Key contains non-ASCII characters", "키에 ASCII가 아닌 문자가 있습니다"),
+    ("No target language is set, so only the source text is shown. Pick a target language to see translations", "번역 대상 언어가 없어 원문만 표시됩니다. 번역을 보려면 대상 언어를 선택하세요"),
+    ("No target language: showing source only", "대상 언어 없음: 원문만 표시"),
+    ("Saved recent audio", "최근 오디오 저장됨"),
+    ("Retro Buffer:", "최근 오디오 버퍼:"),
+    ("Save now", "지금 저장"),
+    ("Keeps the last seconds of microphone audio, even outside a session. The key or button saves them as a WAV in the sessions folder. 0 = off", "세션 밖에서도 마이크 오디오의 마지막 몇 초를 보관합니다. 키나 버튼으로 sessions 폴더에 WAV로 저장합니다. 0 = 끔"),
+    ("Keep Sessions:", "세션 보관:"),
+    (" files", " 개"),
+    ("Deletes the oldest session logs beyond this many, at startup and when a session starts. Only session_*.txt files are counted; a session's subtitle file is deleted with its last log. 0 = keep all", "시작할 때와 세션이 시작될 때 이 개수를 넘는 가장 오래된 세션 로그를 삭제합니다. session_*.txt 파일만 개수에 포함되며, 세션의 자막 파일은 마지막 로그와 함께 삭제됩니다. 0 = 모두 보관"),
+    ("Caption Prefix:", "자막 앞 문구:"),
+    ("Caption Suffix:", "자막 뒤 문구:"),
+    ("Added around each caption on screen only, not in the log. {lang} becomes the displayed language code", "화면의 각 자막 앞뒤에만 추가되며 로그에는 기록되지 않습니다. {lang}은 표시되는 언어 코드로 바뀝니다"),
+    ("Taskbar:", "작업 표시줄:"),
+    ("Hide the overlay from the taskbar", "작업 표시줄에서 오버레이 숨기기"),
+    ("Takes effect after restart. Windows; on Linux (X11) the overlay becomes a utility window, which most window managers leave out of the taskbar and Alt+Tab. Not supported on macOS or Wayland.", "재시작 후 적용됩니다. Windows용이며, Linux(X11)에서는 유틸리티 창이 되어 대부분의 창 관리자가 작업 표시줄과 Alt+Tab에서 제외합니다. macOS와 Wayland는 지원하지 않습니다."),
+    ("Focus:", "포커스:"),
+    ("Don't take focus when the overlay appears", "오버레이가 나타날 때 포커스를 가져오지 않기"),
+    ("Takes effect after restart. Windows and macOS only. Hotkeys still need the overlay to be clicked first.", "재시작 후 적용됩니다. Windows와 macOS 전용입니다. 단축키를 쓰려면 먼저 오버레이를 클릭해야 합니다."),
+    ("Upload As:", "업로드 방식:"),
+    ("Multipart", "멀티파트"),
+    ("Base64 JSON posts {\"audio\": \"<base64 wav>\", \"model\", \"language\"} as application/json, for serverless endpoints that don't accept multipart. The payload is about a third larger", "Base64 JSON은 {\"audio\": \"<base64 wav>\", \"model\", \"language\"}를 application/json으로 보냅니다. 멀티파트를 받지 않는 서버리스 엔드포인트용이며, 크기가 약 3분의 1 커집니다"),
+    ("Model", "모델"),
+    ("Model Shortlist:", "모델 후보:"),
+    ("One model per line. The key switches the chat model to the next one on the list during a session", "한 줄에 모델 하나씩 입력합니다. 세션 중 키를 누르면 목록의 다음 모델로 채팅 모델이 바뀝니다"),
+    ("Merge Sentences:", "문장 병합:"),
+    ("Join log entries that continue the same sentence", "같은 문장이 이어지는 로그 항목 합치기"),
+    ("When a phrase has no end punctuation and the next starts lowercase, they are written as one log entry. Captions are still shown per phrase; each entry is written once the next phrase arrives", "구절이 문장 부호 없이 끝나고 다음 구절이 소문자로 시작하면 하나의 로그 항목으로 기록합니다. 자막은 구절마다 표시되며, 각 항목은 다음 구절이 오면 기록됩니다"),
+    ("Auto Gain:", "자동 게인:"),
+    ("level", "레벨"),
+    ("Evens out the input level as the speaker moves relative to the mic, before voice detection. Gain is held during silence so pauses aren't amplified into false triggers. Retune the silence threshold after turning this on", "화자가 마이크에서 멀어지거나 가까워져도 음성 감지 전에 입력 레벨을 고르게 맞춥니다. 무음 중에는 게인을 유지하여 쉬는 구간이 잘못 감지되지 않게 합니다. 켠 뒤에는 무음 임계값을 다시 조정하세요"),
+    ("Gain Timing:", "게인 속도:"),
+    ("attack", "어택"),
+    ("release", "릴리스"),
+    ("Placeholder:", "대기 표시:"),
+    ("Animate dots every", "점 애니메이션 간격"),
+    ("Caption Pipe:", "자막 파이프:"),
+    ("Streams each caption as a JSON line to local consumers: a named pipe on Windows, a Unix socket in the temp folder elsewhere. Takes effect after restart", "각 자막을 JSON 한 줄로 로컬 프로그램에 보냅니다. Windows에서는 명명된 파이프, 그 외에는 임시 폴더의 Unix 소켓입니다. 재시작 후 적용됩니다"),
+    ("Warming up…", "준비 중…"),
+    ("Startup Grace:", "시작 유예:"),
+    ("Speech in the first seconds of a session (mic handling, \"testing\") is not transcribed or logged. 0 = off", "세션 시작 후 처음 몇 초 동안의 말(마이크 조정, \"테스트\")은 받아쓰거나 기록하지 않습니다. 0 = 끔"),
+    ("Prompt Overrides:", "프롬프트 재정의:"),
+    ("Add for language…", "언어별 추가…"),
+    ("Translation instructions used instead of the default when that language is the target. {target} is replaced with the language code", "해당 언어가 번역 대상일 때 기본값 대신 사용할 번역 지시문입니다. {target}은 언어 코드로 바뀝니다"),
+    ("Confidence Colors:", "신뢰도 색상:"),
+    ("green from", "초록 기준"),
+    ("yellow from", "노랑 기준"),
+    ("Tints each caption by how confident the transcription was (green, yellow, red). Requests verbose_json; servers that don't return segment probabilities keep the normal text color", "받아쓰기 신뢰도에 따라 자막에 색을 입힙니다(초록, 노랑, 빨강). verbose_json을 요청하며, 구간 확률을 반환하지 않는 서버에서는 기본 글자 색을 유지합니다"),
+    ("Only When Visible:", "표시 중에만:"),
+    ("Ignore speech while the window is minimized or hidden", "창이 최소화되거나 숨겨진 동안 음성 무시"),
+    ("Transcription failed", "전사 실패"),
+    ("Translation failed", "번역 실패"),
+    ("Refine failed", "다듬기 실패"),
+    ("Cleanup failed", "정리 실패"),
+    ("timeout", "시간 초과"),
+    ("connection error", "연결 오류"),
+    ("request error", "요청 오류"),
+    ("invalid response", "잘못된 응답"),
+    ("Transcribe Model:", "전사 모델:"),
+    ("e.g. whisper-1 for OpenAI or Systran/faster-whisper-medium for faster-whisper-server. Empty uses large-v3", "예: OpenAI는 whisper-1, faster-whisper-server는 Systran/faster-whisper-medium. 비워 두면 large-v3 사용"),
+    ("Glossary:", "용어집:"),
+    ("Domain terms sent to the transcription API as a prompt and to the translator as spellings to keep. Translations are also corrected to these exact spellings (e.g. \"dna\" becomes \"DNA\")", "전사 API에 프롬프트로, 번역기에 유지할 철자로 전달되는 전문 용어. 번역도 이 철자대로 고쳐집니다(예: \"dna\" → \"DNA\")"),
+    ("Copy caption", "자막 복사"),
+    ("Caption copied", "자막을 복사했습니다"),
+    ("Session Text:", "세션 텍스트:"),
+    ("Export…", "내보내기…"),
+    ("Saves everything captioned in the running session to a file of your choice", "진행 중인 세션의 모든 자막을 원하는 파일로 저장"),
+    ("Session exported", "세션을 내보냈습니다"),
+    ("Export failed", "내보내기 실패"),
+    ("End of Phrase:", "구문 종료:"),
+    ("Pause that ends a phrase. Lower for fast speakers with short pauses, higher for slow deliberate speakers", "구문을 끝내는 쉼 길이. 쉼이 짧은 빠른 화자는 낮게, 천천히 말하는 화자는 높게"),
+    ("Max Phrase Length:", "최대 구문 길이:"),
+    ("Speech without a pause is cut and sent after this long", "쉼 없이 이어지는 음성은 이 시간이 지나면 잘라서 전송"),
+    ("Input Level:", "입력 레벨:"),
+    ("Live microphone level. Set the threshold (white mark) just above the level shown while nobody speaks", "실시간 마이크 레벨. 아무도 말하지 않을 때 표시되는 레벨보다 약간 높게 임계값(흰색 표시)을 설정하세요"),
+    ("Term Translations:", "용어 번역:"),
+    ("term", "용어"),
+    ("keep as is", "그대로 유지"),
+    ("Add term", "용어 추가"),
+    ("The translator is told to always translate each term this way. Leave the translation empty to keep a name or acronym untranslated", "번역기가 각 용어를 항상 이렇게 번역하도록 지시합니다. 이름이나 약어를 번역하지 않으려면 번역을 비워 두세요"),
+    ("Retries:", "재시도:"),
+    ("Retries after a server error or dropped connection, waiting 200 ms, 400 ms, 800 ms, ... in between. 0 for the lowest latency", "서버 오류나 연결 끊김 후 재시도 횟수. 사이에 200 ms, 400 ms, 800 ms, ... 대기. 지연을 최소화하려면 0"),
+    ("Subtitles:", "자막 파일:"),
+    ("Original", "원문"),
+    ("Translation", "번역"),
+    ("Also writes each session as a subtitle file next to its log, one cue per phrase timed from the session start, for captioning a recording afterward. Applies from the next session", "각 세션을 로그 옆에 자막 파일로도 저장합니다. 구문마다 세션 시작 기준 시간의 큐 하나로, 녹화본에 자막을 달 때 사용합니다. 다음 세션부터 적용"),
+    ("Rolling Captions:", "누적 자막:"),
+    (" phrases", " 구문"),
+    ("Keeps the last phrases on screen, newest at the bottom, so a reader who looked away can catch up. The font shrinks to fit them all. 1 = only the latest phrase", "최근 구문들을 화면에 남겨 두어(최신이 맨 아래) 잠시 눈을 뗀 사람도 따라잡을 수 있습니다. 모두 들어가도록 글꼴이 줄어듭니다. 1 = 최신 구문만"),
+    ("On", "사용"),
+    ("Click-through", "클릭 통과"),
+    ("Click-through:", "클릭 통과:"),
+    ("Let clicks pass to the window underneath", "클릭을 아래 창으로 통과시키기"),
+    ("The overlay's buttons and drag/resize stop working too. To turn it off, press the global click-through hotkey from any app, focus the overlay and press this key, or untick it here", "오버레이 버튼과 드래그/크기 조절도 작동하지 않습니다. 끄려면 어느 앱에서든 전역 클릭 통과 단축키를 누르거나, 오버레이에 포커스를 준 뒤 이 키를 누르거나, 여기서 체크를 해제하세요"),
+    ("Global Hotkeys:", "전역 단축키:"),
+    ("Show/Hide", "표시/숨기기"),
+    ("Work even while another app has focus. Leave empty to disable. A key already taken by another app is skipped", "다른 앱에 포커스가 있어도 작동합니다. 비워 두면 사용하지 않습니다. 다른 앱이 이미 사용 중인 키는 건너뜁니다"),
+    ("Session", "세션"),
+    ("Context:", "문맥:"),
+    ("Recent phrases sent along with each translation. More keeps terms and pronouns consistent through a lecture; fewer avoids carrying stale context across topic jumps in a Q&A. 0 = no context. Cleared when a session starts", "번역할 때 함께 보내는 최근 구문 수입니다. 많으면 강연 내내 용어와 대명사가 일관되고, 적으면 질의응답처럼 주제가 바뀔 때 지난 문맥이 섞이지 않습니다. 0 = 문맥 없음. 세션을 시작하면 지워집니다"),
+    ("Subtitle Offset:", "자막 오프셋:"),
+    ("Shifts every cue in the subtitle file, e.g. when the recording started before or after the session. Negative values make cues earlier", "자막 파일의 모든 큐를 이동합니다. 예를 들어 녹화가 세션보다 먼저 또는 나중에 시작된 경우에 사용합니다. 음수 값은 큐를 앞당깁니다"),
+    ("Resampling:", "리샘플링:"),
+    ("Linear", "선형"),
+    ("How phrases are converted to 16 kHz for upload. Sinc is more faithful, which can help tonal languages, at a higher but still small CPU cost; Linear is enough on low-end machines", "업로드를 위해 구문을 16 kHz로 변환하는 방식입니다. Sinc는 더 충실해서 성조 언어에 도움이 될 수 있으며 CPU 사용량은 늘지만 여전히 적습니다. 저사양 PC에서는 선형으로 충분합니다"),
+    ("Filename sent with the upload, for servers that route on it. The extension is always .wav to match the audio", "서버가 파일 이름으로 처리 방식을 정하는 경우를 위해 업로드와 함께 보내는 파일 이름입니다. 확장자는 오디오에 맞게 항상 .wav입니다"),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("LiveCapTran Settings", "Ajustes de LiveCapTran"),
+    ("UI Language:", "Idioma de la interfaz:"),
+    ("Transcribe API URL:", "URL de la API de transcripción:"),
+    ("Transcribe API Key:", "Clave de la API de transcripción:"),
+    ("Transcribe Timeout:", "Tiempo límite de transcripción:"),
+    ("Upload Filename:", "Nombre del archivo subido:"),
+    ("Language:", "Idioma:"),
+    ("Candidate Languages:", "Idiomas candidatos:"),
+    ("Silence Threshold:", "Umbral de silencio:"),
+    ("Font Size:", "Tamaño de letra:"),
+    ("Min Font Size:", "Tamaño mínimo de letra:"),
+    ("Overflow:", "Desbordamiento:"),
+    ("Scroll to newest text instead of shrinking further", "Desplazar al texto más reciente en vez de reducir más"),
+    ("Font Smoothing:", "Suavizado de tamaño:"),
+    ("Ease size changes", "Suavizar los cambios de tamaño"),
+    ("Chat API URL:", "URL de la API de chat:"),
+    ("Chat API Key:", "Clave de la API de chat:"),
+    ("Chat Model:", "Modelo de chat:"),
+    ("Chat Timeout:", "Tiempo límite de chat:"),
+    ("Cleanup:", "Limpieza:"),
+    ("Remove fillers and fix punctuation (adds latency)", "Quitar muletillas y corregir la puntuación (añade latencia)"),
+    ("Cleanup Prompt:", "Instrucción de limpieza:"),
+    ("Translate To:", "Traducir a:"),
+    ("None", "Ninguno"),
+    ("Casing:", "Mayúsculas:"),
+    ("As translated", "Como se tradujo"),
+    ("Sentence case", "Tipo oración"),
+    ("Display:", "Mostrar:"),
+    ("Both", "Ambos"),
+    ("Translation only", "Solo traducción"),
+    ("Display Mode Key:", "Tecla de modo de vista:"),
+    ("Cycles the display mode while the overlay has focus. Leave empty to disable.", "Cambia el modo de vista cuando la superposición tiene el foco. Déjelo vacío para desactivarlo."),
+    ("Numeric Phrases:", "Frases numéricas:"),
+    ("Translate", "Traducir"),
+    ("Keep verbatim", "Mantener literal"),
+    ("Show source", "Mostrar original"),
+    ("Numeric Ratio:", "Proporción numérica:"),
+    ("Opacity:", "Opacidad:"),
+    ("Audio Host:", "Host de audio:"),
+    ("Default", "Predeterminado"),
+    ("Takes effect after restart", "Se aplica tras reiniciar"),
+    ("Input Device:", "Dispositivo de entrada:"),
+    ("Detect Mute:", "Detectar silencio:"),
+    ("Treat silent (all-zero) input as muted", "Tratar la entrada en silencio absoluto como silenciada"),
+    ("Clock:", "Reloj:"),
+    ("Show", "Mostrar"),
+    ("Wall clock", "Hora actual"),
+    ("Session elapsed", "Tiempo de sesión"),
+    ("Clock Format:", "Formato del reloj:"),
+    ("Clock Corner:", "Esquina del reloj:"),
+    ("Top left", "Arriba a la izquierda"),
+    ("Top right", "Arriba a la derecha"),
+    ("Bottom left", "Abajo a la izquierda"),
+    ("Bottom right", "Abajo a la derecha"),
+    ("Noise Filter:", "Filtro de ruido:"),
+    ("Discard results with no words (punctuation, symbols, emoji)", "Descartar resultados sin palabras (puntuación, símbolos, emoji)"),
+    ("Extra Noise Chars:", "Caracteres de ruido extra:"),
+    ("Status Bar:", "Barra de estado:"),
+    ("Top", "Arriba"),
+    ("Bottom", "Abajo"),
+    ("Processing Indicator:", "Indicador de proceso:"),
+    ("Show a spinner while a phrase is processed", "Mostrar un indicador mientras se procesa una frase"),
+    ("Upload Progress:", "Progreso de subida:"),
+    ("Show a progress bar while audio uploads", "Mostrar una barra de progreso al subir audio"),
+    ("Auto Start:", "Inicio automático:"),
+    ("Start a session on launch", "Iniciar una sesión al abrir"),
+    ("Cut Overlap:", "Solapamiento al cortar:"),
+    ("Audio carried into the next phrase when a long phrase is cut", "Audio que pasa a la frase siguiente al cortar una frase larga"),
+    ("Merge Fragments:", "Unir fragmentos:"),
+    ("Within", "Dentro de"),
+    ("Re-transcribe a short phrase together with one that follows it closely", "Volver a transcribir una frase corta junto con la que la sigue de cerca"),
+    ("Repeated Text:", "Texto repetido:"),
+    ("Keep", "Mantener"),
+    ("Skip", "Omitir"),
+    ("New part only", "Solo la parte nueva"),
+    ("Repeat Similarity:", "Similitud de repetición:"),
+    ("Debug Log:", "Registro de depuración:"),
+    ("Write debug.log", "Escribir debug.log"),
+    ("Include bodies", "Incluir cuerpos"),
+    ("Low Power:", "Bajo consumo:"),
+    ("Poll and repaint less often (adds latency)", "Sondear y redibujar con menos frecuencia (añade latencia)"),
+    ("Control API:", "API de control:"),
+    ("Enable", "Activar"),
+    ("All Workspaces:", "Todos los escritorios:"),
+    ("Show on every virtual desktop", "Mostrar en todos los escritorios virtuales"),
+    ("Windows and Linux (X11) only. On Windows this also hides the overlay from the taskbar.", "Solo Windows y Linux (X11). En Windows también oculta la superposición de la barra de tareas."),
+    ("Turn Markers:", "Marcas de turno:"),
+    ("Mark gaps over", "Marcar pausas de más de"),
+    ("Writes a separator to the session log before a phrase that follows a long pause", "Escribe un separador en el registro antes de una frase que sigue a una pausa larga"),
+    ("Caption Queue:", "Cola de subtítulos:"),
+    ("Show each for", "Mostrar cada uno"),
+    ("Holds captions that finish while the previous one is still on screen instead of replacing it", "Retiene los subtítulos que terminan mientras el anterior sigue en pantalla en vez de reemplazarlo"),
+    ("Max Queued:", "Máximo en cola:"),
+    ("Oldest pending captions are dropped beyond this", "Por encima de este número se descartan los subtítulos pendientes más antiguos"),
+    ("Display", "Vista"),
+    ("Recording", "Grabando"),
+    ("Idle", "Inactivo"),
+    ("Connection Pool:", "Grupo de conexiones:"),
+    ("idle", "inactivas"),
+    ("Idle connections kept open per API host, and for how long. 0 opens a new connection for every request.", "Conexiones inactivas abiertas por host de API y durante cuánto tiempo. 0 abre una conexión nueva en cada petición."),
+    ("HTTP/2:", "HTTP/2:"),
+    ("Use HTTP/2 when the server supports it", "Usar HTTP/2 si el servidor lo admite"),
+    ("Lower Third:", "Tercio inferior:"),
+    ("Bottom band", "Banda inferior"),
+    ("Draw the background and caption only in a fixed-height band along the bottom", "Dibujar el fondo y el subtítulo solo en una banda de altura fija en la parte inferior"),
+    ("Physical Size:", "Tamaño físico:"),
+    ("Keep size across monitors with different scaling", "Mantener el tamaño entre monitores con distinta escala"),
+    ("Sample Rate:", "Frecuencia de muestreo:"),
+    ("Falls back to the device default if unsupported. Takes effect after restart", "Si no se admite, se usa la del dispositivo. Se aplica tras reiniciar"),
+    ("Log Flush:", "Volcado del registro:"),
+    ("Every line", "Cada línea"),
+    ("Every few seconds", "Cada pocos segundos"),
+    ("On session end", "Al terminar la sesión"),
+    ("Less frequent flushing helps on network drives, but a crash can lose the unflushed lines", "Volcar con menos frecuencia ayuda en unidades de red, pero un fallo puede perder las líneas pendientes"),
+    ("Controls:", "Controles:"),
+    ("Hidden", "Ocultos"),
+    ("Settings Key:", "Tecla de ajustes:"),
+    ("Close Key:", "Tecla de cierre:"),
+    ("Whole Sentences:", "Frases completas:"),
+    ("Translate at sentence end, or after", "Traducir al final de la frase, o tras"),
+    ("Fragments are shown untranslated until the sentence is complete", "Los fragmentos se muestran sin traducir hasta completar la frase"),
+    ("Threshold Keys:", "Teclas de umbral:"),
+    ("Raise or lower the silence threshold by 20% during a session", "Subir o bajar el umbral de silencio un 20% durante la sesión"),
+    ("Silence Threshold", "Umbral de silencio"),
+    ("Refine Model:", "Modelo de revisión:"),
+    ("Off", "Desactivado"),
+    ("A second model reviews each translation against the source. Adds latency and cost", "Un segundo modelo revisa cada traducción frente al original. Añade latencia y coste"),
+    ("Learner Mode:", "Modo aprendizaje:"),
+    ("Hide translation until revealed, key:", "Ocultar la traducción hasta revelarla, tecla:"),
+    ("In Both mode the translation is obscured until you hover the caption or press the reveal key", "En modo Ambos la traducción se oculta hasta pasar el ratón por el subtítulo o pulsar la tecla"),
+    ("Startup:", "Inicio:"),
+    ("Stay transparent for the first frames (avoids a black flash)", "Mantener transparente los primeros fotogramas (evita un destello negro)"),
+    ("Strip Quotes:", "Quitar comillas:"),
+    ("Outer pair", "Par exterior"),
+    ("All", "Todas"),
+    ("Never", "Nunca"),
+    ("Reactive Opacity:", "Opacidad reactiva:"),
+    ("Fade to", "Atenuar a"),
+    ("when silent", "en silencio"),
+    ("History:", "Historial:"),
+    ("Include previous translations as context", "Incluir las traducciones anteriores como contexto"),
+    ("Turn off if the model drifts when fed its own output; only the previous source text is sent then", "Desactívelo si el modelo se desvía con su propia salida; entonces solo se envía el texto original anterior"),
+    ("Rate limited, retrying in", "Límite de peticiones, reintento en"),
+    ("Rate Limits:", "Límites de peticiones:"),
+    ("max wait", "espera máx."),
+    ("Show source instead of waiting", "Mostrar el original en vez de esperar"),
+    ("On HTTP 429 the request is retried after the server's Retry-After delay, up to the max wait", "Con HTTP 429 la petición se reintenta tras el Retry-After del servidor, hasta la espera máxima"),
+    ("Emphasis:", "Énfasis:"),
+    ("size", "tamaño"),
+    ("The key marks the current caption as important until the next phrase", "La tecla marca el subtítulo actual como importante hasta la siguiente frase"),
+    ("Split Log:", "Dividir registro:"),
+    ("Continue long sessions in session_<time>_partN.txt files. 0 keeps one file", "Continuar las sesiones largas en archivos session_<hora>_partN.txt. 0 mantiene un solo archivo"),
+    ("Schedule:", "Horario:"),
+    ("Run from", "Activo de"),
+    ("to", "a"),
+    ("Starts the session when the window opens (also when launched inside it) and stops it when it closes. An end before the start crosses midnight", "Inicia la sesión al abrirse la franja (también si se abre dentro de ella) y la detiene al cerrarse. Un fin anterior al inicio cruza la medianoche"),
+    ("Compare API URL:", "URL de API de comparación:"),
+    ("Compare API Key:", "Clave de API de comparación:"),
+    ("Also sends each phrase to this endpoint and logs both results, labeled, in the session log. Only the primary is displayed", "También envía cada frase a este endpoint y registra ambos resultados, etiquetados, en el registro de sesión. Solo se muestra el principal"),
+    ("Normalize Audio:", "Normalizar audio:"),
+    ("peak", "pico"),
+    ("Boosts quiet phrases so their peak reaches the target before upload. Voice detection still uses the unprocessed input", "Amplifica las frases bajas para que su pico alcance el objetivo antes de enviarlas. La detección de voz sigue usando la entrada sin procesar"),
+    ("Text Color:", "Color del texto:"),
+    ("Chroma Key:", "Croma:"),
+    ("Fills the window with a solid key color instead of the translucent background, for keying out in OBS. Pick a text color that contrasts with it", "Rellena la ventana con un color clave sólido en lugar del fondo translúcido, para recortarlo en OBS. Elige un color de texto que contraste"),
+    ("Long Words:", "Palabras largas:"),
+    ("Break anywhere instead of shrinking the font", "Cortar en cualquier punto en lugar de reducir la fuente"),
+    ("Lets long URLs and compound words wrap mid-token. Any word at the line edge may then be split", "Permite que URL largas y palabras compuestas se corten a mitad. Cualquier palabra al final de la línea puede dividirse"),
+    ("Show key", "Mostrar clave"),
+    ("Key has leading or trailing spaces", "La clave tiene espacios al inicio o al final"),
+    ("Key contains spaces or line breaks", "La clave contiene espacios o saltos de línea"),
+    ("Key is wrapped in quotes", "La clave está entre comillas"),
+    ("Remove the \"Bearer\" prefix; it is added automatically", "Quita el prefijo \"Bearer\"; se añade automáticamente"),
+    ("Key contains non-ASCII characters", "La clave contiene caracteres no ASCII"),
+    ("No target language is set, so only the source text is shown. Pick a target language to see translations", "No hay idioma de destino, así que solo se muestra el texto original. Elige un idioma de destino para ver traducciones"),
+    ("No target language: showing source only", "Sin idioma de destino: solo texto original"),
+    ("Saved recent audio", "Audio reciente guardado"),
+    ("Retro Buffer:", "Búfer retroactivo:"),
+    ("Save now", "Guardar ahora"),
+    ("Keeps the last seconds of microphone audio, even outside a session. The key or button saves them as a WAV in the sessions folder. 0 = off", "Conserva los últimos segundos de audio del micrófono, incluso fuera de una sesión. La tecla o el botón los guardan como WAV en la carpeta sessions. 0 = desactivado"),
+    ("Keep Sessions:", "Conservar sesiones:"),
+    (" files", " archivos"),
+    ("Deletes the oldest session logs beyond this many, at startup and when a session starts. Only session_*.txt files are counted; a session's subtitle file is deleted with its last log. 0 = keep all", "Borra los registros de sesión más antiguos que superen esta cantidad, al iniciar y al empezar una sesión. Solo cuentan los archivos session_*.txt; el archivo de subtítulos de una sesión se borra con su último registro. 0 = conservar todo"),
+    ("Caption Prefix:", "Prefijo del subtítulo:"),
+    ("Caption Suffix:", "Sufijo del subtítulo:"),
+    ("Added around each caption on screen only, not in the log. {lang} becomes the displayed language code", "Se añade alrededor de cada subtítulo solo en pantalla, no en el registro. {lang} se sustituye por el código del idioma mostrado"),
+    ("Taskbar:", "Barra de tareas:"),
+    ("Hide the overlay from the taskbar", "Ocultar la superposición de la barra de tareas"),
+    ("Takes effect after restart. Windows; on Linux (X11) the overlay becomes a utility window, which most window managers leave out of the taskbar and Alt+Tab. Not supported on macOS or Wayland.", "Se aplica tras reiniciar. Windows; en Linux (X11) la superposición pasa a ser una ventana de utilidad, que la mayoría de gestores de ventanas excluyen de la barra de tareas y de Alt+Tab. No compatible con macOS ni Wayland."),
+    ("Focus:", "Foco:"),
+    ("Don't take focus when the overlay appears", "No tomar el foco al aparecer la superposición"),
+    ("Takes effect after restart. Windows and macOS only. Hotkeys still need the overlay to be clicked first.", "Se aplica tras reiniciar. Solo Windows y macOS. Los atajos siguen requiriendo hacer clic antes en la superposición."),
+    ("Upload As:", "Enviar como:"),
+    ("Multipart", "Multipart"),
+    ("Base64 JSON", "JSON Base64"),
+    ("Base64 JSON posts {\"audio\": \"<base64 wav>\", \"model\", \"language\"} as application/json, for serverless endpoints that don't accept multipart. The payload is about a third larger", "Base64 JSON envía {\"audio\": \"<base64 wav>\", \"model\", \"language\"} como application/json, para endpoints serverless que no aceptan multipart. La carga es aproximadamente un tercio mayor"),
+    ("Model", "Modelo"),
+    ("Model Shortlist:", "Lista de modelos:"),
+    ("One model per line. The key switches the chat model to the next one on the list during a session", "Un modelo por línea. La tecla cambia el modelo de chat al siguiente de la lista durante una sesión"),
+    ("Merge Sentences:", "Unir frases:"),
+    ("Join log entries that continue the same sentence", "Unir entradas del registro que continúan la misma frase"),
+    ("When a phrase has no end punctuation and the next starts lowercase, they are written as one log entry. Captions are still shown per phrase; each entry is written once the next phrase arrives", "Si una frase no termina en puntuación y la siguiente empieza en minúscula, se escriben como una sola entrada. Los subtítulos se siguen mostrando por frase; cada entrada se escribe al llegar la siguiente frase"),
+    ("Auto Gain:", "Ganancia automática:"),
+    ("level", "nivel"),
+    ("Evens out the input level as the speaker moves relative to the mic, before voice detection. Gain is held during silence so pauses aren't amplified into false triggers. Retune the silence threshold after turning this on", "Iguala el nivel de entrada cuando el orador se mueve respecto al micrófono, antes de la detección de voz. La ganancia se mantiene en silencio para que las pausas no se amplifiquen en falsos disparos. Reajusta el umbral de silencio al activarlo"),
+    ("Gain Timing:", "Tiempos de ganancia:"),
+    ("attack", "ataque"),
+    ("release", "liberación"),
+    ("Placeholder:", "Marcador de espera:"),
+    ("Animate dots every", "Animar puntos cada"),
+    ("Caption Pipe:", "Canalización de subtítulos:"),
+    ("Streams each caption as a JSON line to local consumers: a named pipe on Windows, a Unix socket in the temp folder elsewhere. Takes effect after restart", "Envía cada subtítulo como una línea JSON a consumidores locales: una canalización con nombre en Windows y un socket Unix en la carpeta temporal en los demás. Se aplica tras reiniciar"),
+    ("Warming up…", "Preparando…"),
+    ("Startup Grace:", "Margen inicial:"),
+    ("Speech in the first seconds of a session (mic handling, \"testing\") is not transcribed or logged. 0 = off", "Lo que se diga en los primeros segundos de una sesión (ajustar el micrófono, \"probando\") no se transcribe ni se registra. 0 = desactivado"),
+    ("Prompt Overrides:", "Prompts por idioma:"),
+    ("Add for language…", "Añadir para idioma…"),
+    ("Translation instructions used instead of the default when that language is the target. {target} is replaced with the language code", "Instrucciones de traducción que sustituyen a las predeterminadas cuando ese idioma es el destino. {target} se sustituye por el código del idioma"),
+    ("Confidence Colors:", "Colores de confianza:"),
+    ("green from", "verde desde"),
+    ("yellow from", "amarillo desde"),
+    ("Tints each caption by how confident the transcription was (green, yellow, red). Requests verbose_json; servers that don't return segment probabilities keep the normal text color", "Colorea cada subtítulo según la confianza de la transcripción (verde, amarillo, rojo). Solicita verbose_json; con servidores que no devuelven probabilidades por segmento se mantiene el color normal"),
+    ("Only When Visible:", "Solo si está visible:"),
+    ("Ignore speech while the window is minimized or hidden", "Ignorar la voz mientras la ventana está minimizada u oculta"),
+    ("Transcription failed", "Error de transcripción"),
+    ("Translation failed", "Error de traducción"),
+    ("Refine failed", "Error de revisión"),
+    ("Cleanup failed", "Error de limpieza"),
+    ("timeout", "tiempo agotado"),
+    ("connection error", "error de conexión"),
+    ("request error", "error de solicitud"),
+    ("invalid response", "respuesta no válida"),
+    ("Transcribe Model:", "Modelo de transcripción:"),
+    ("e.g. whisper-1 for OpenAI or Systran/faster-whisper-medium for faster-whisper-server. Empty uses large-v3", "p. ej. whisper-1 para OpenAI o Systran/faster-whisper-medium para faster-whisper-server. Vacío usa large-v3"),
+    ("Glossary:", "Glosario:"),
+    ("Domain terms sent to the transcription API as a prompt and to the translator as spellings to keep. Translations are also corrected to these exact spellings (e.g. \"dna\" becomes \"DNA\")", "Términos del dominio enviados a la API de transcripción como prompt y al traductor como grafías a conservar. Las traducciones también se corrigen a estas grafías exactas (p. ej., \"dna\" pasa a \"DNA\")"),
+    ("Copy caption", "Copiar subtítulo"),
+    ("Caption copied", "Subtítulo copiado"),
+    ("Session Text:", "Texto de la sesión:"),
+    ("Export…", "Exportar…"),
+    ("Saves everything captioned in the running session to a file of your choice", "Guarda todo lo subtitulado en la sesión en curso en el archivo que elija"),
+    ("Session exported", "Sesión exportada"),
+    ("Export failed", "Error al exportar"),
+    ("End of Phrase:", "Fin de frase:"),
+    ("Pause that ends a phrase. Lower for fast speakers with short pauses, higher for slow deliberate speakers", "Pausa que termina una frase. Más baja para oradores rápidos con pausas cortas, más alta para oradores lentos"),
+    ("Max Phrase Length:", "Duración máxima de frase:"),
+    ("Speech without a pause is cut and sent after this long", "El habla sin pausas se corta y se envía tras este tiempo"),
+    ("Input Level:", "Nivel de entrada:"),
+    ("Live microphone level. Set the threshold (white mark) just above the level shown while nobody speaks", "Nivel del micrófono en vivo. Ajuste el umbral (marca blanca) justo por encima del nivel mostrado cuando nadie habla"),
+    ("Term Translations:", "Traducciones de términos:"),
+    ("term", "término"),
+    ("keep as is", "mantener tal cual"),
+    ("Add term", "Añadir término"),
+    ("The translator is told to always translate each term this way. Leave the translation empty to keep a name or acronym untranslated", "Se indica al traductor que traduzca siempre cada término así. Deje la traducción vacía para mantener un nombre o sigla sin traducir"),
+    ("Retries:", "Reintentos:"),
+    ("Retries after a server error or dropped connection, waiting 200 ms, 400 ms, 800 ms, ... in between. 0 for the lowest latency", "Reintentos tras un error del servidor o una conexión caída, esperando 200 ms, 400 ms, 800 ms, ... entre ellos. 0 para la menor latencia"),
+    ("Subtitles:", "Subtítulos:"),
+    ("Original", "Original"),
+    ("Translation", "Traducción"),
+    ("Also writes each session as a subtitle file next to its log, one cue per phrase timed from the session start, for captioning a recording afterward. Applies from the next session", "También guarda cada sesión como archivo de subtítulos junto a su registro, una entrada por frase con tiempos desde el inicio de la sesión, para subtitular una grabación después. Se aplica desde la siguiente sesión"),
+    ("Rolling Captions:", "Subtítulos acumulados:"),
+    (" phrases", " frases"),
+    ("Keeps the last phrases on screen, newest at the bottom, so a reader who looked away can catch up. The font shrinks to fit them all. 1 = only the latest phrase", "Mantiene en pantalla las últimas frases, la más reciente abajo, para que quien apartó la vista pueda ponerse al día. La fuente se reduce para que quepan todas. 1 = solo la última frase"),
+    ("On", "Activado"),
+    ("Click-through", "Clic a través"),
+    ("Click-through:", "Clic a través:"),
+    ("Let clicks pass to the window underneath", "Dejar pasar los clics a la ventana de abajo"),
+    ("The overlay's buttons and drag/resize stop working too. To turn it off, press the global click-through hotkey from any app, focus the overlay and press this key, or untick it here", "Los botones de la superposición y el arrastre/redimensionado también dejan de funcionar. Para desactivarlo, pulse el atajo global de clic a través desde cualquier aplicación, enfoque la superposición y pulse esta tecla, o desmárquelo aquí"),
+    ("Global Hotkeys:", "Atajos globales:"),
+    ("Show/Hide", "Mostrar/Ocultar"),
+    ("Work even while another app has focus. Leave empty to disable. A key already taken by another app is skipped", "Funcionan aunque otra aplicación tenga el foco. Déjalo vacío para desactivarlo. Una tecla ya usada por otra aplicación se omite"),
+    ("Session", "Sesión"),
+    ("Context:", "Contexto:"),
+    ("Recent phrases sent along with each translation. More keeps terms and pronouns consistent through a lecture; fewer avoids carrying stale context across topic jumps in a Q&A. 0 = no context. Cleared when a session starts", "Frases recientes enviadas con cada traducción. Más mantiene coherentes los términos y pronombres en una conferencia; menos evita arrastrar contexto obsoleto entre cambios de tema en una ronda de preguntas. 0 = sin contexto. Se borra al iniciar una sesión"),
+    ("Subtitle Offset:", "Desfase de subtítulos:"),
+    ("Shifts every cue in the subtitle file, e.g. when the recording started before or after the session. Negative values make cues earlier", "Desplaza todas las entradas del archivo de subtítulos, p. ej. si la grabación empezó antes o después de la sesión. Los valores negativos las adelantan"),
+    ("Resampling:", "Remuestreo:"),
+    ("Linear", "Lineal"),
+    ("How phrases are converted to 16 kHz for upload. Sinc is more faithful, which can help tonal languages, at a higher but still small CPU cost; Linear is enough on low-end machines", "Cómo se convierten las frases a 16 kHz para subirlas. Sinc es más fiel, lo que puede ayudar con idiomas tonales, con un coste de CPU mayor pero aún pequeño; Lineal basta en equipos modestos"),
+    ("Filename sent with the upload, for servers that route on it. The extension is always .wav to match the audio", "Nombre de archivo enviado con la subida, para servidores que se guían por él. La extensión es siempre .wav para coincidir con el audio"),
+];
@@ -2,19 +2,54 @@
 
 mod app;
 mod audio;
+mod control;
+mod i18n;
+mod pipe;
+mod platform;
+mod schedule;
 mod settings;
+mod text;
 
 use eframe::egui;
 
 fn main() -> eframe::Result {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => match args.next() {
+                Some(path) => settings::set_config_path(path.into()),
+                None => eprintln!("--config requires a path"),
+            },
+            "--input-file" => match args.next() {
+                Some(path) => audio::set_input_file(path.into()),
+                None => eprintln!("--input-file requires a path"),
+            },
+            other => eprintln!("Ignoring unknown argument: {other}"),
+        }
+    }
+
+    let startup = settings::Settings::load();
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size([3840.0, 500.0])
+        .with_position([0.0, 0.0])
+        .with_decorations(false)
+        .with_transparent(true)
+        .with_resizable(true)
+        .with_always_on_top();
+    // HUD behavior. The taskbar hint is honored on Windows; X11 window managers
+    // generally leave utility windows out of the taskbar and Alt+Tab instead.
+    // The focus hint works on Windows and macOS only.
+    if startup.skip_taskbar {
+        viewport = viewport
+            .with_taskbar(false)
+            .with_window_type(egui::X11WindowType::Utility);
+    }
+    if startup.no_focus {
+        viewport = viewport.with_active(false);
+    }
+
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([3840.0, 500.0])
-            .with_position([0.0, 0.0])
-            .with_decorations(false)
-            .with_transparent(true)
-            .with_resizable(true)
-            .with_always_on_top(),
+        viewport,
         ..Default::default()
     };
 
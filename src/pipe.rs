@@ -0,0 +1,124 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::audio::recover_poison;
+
+// How often the transcript is checked for a new caption
+const PIPE_POLL_MS: u64 = 10;
+
+// Local caption pipe for tightly coupled consumers (a renderer on the same
+// machine). Every caption change is written as one JSON line: {"text": "..."}
+//
+// - Windows: named pipe \\.\pipe\<name>, one client at a time
+// - Unix: Unix domain socket <temp dir>/<name> (or <name> if it's an absolute
+//   path), any number of clients
+pub fn start_caption_pipe(name: String, transcript: Arc<Mutex<String>>) {
+    thread::spawn(move || {
+        if let Err(e) = serve(&name, &transcript) {
+            eprintln!("Caption pipe '{name}' stopped: {e}");
+        }
+    });
+}
+
+fn caption_line(text: &str) -> String {
+    format!("{}\n", serde_json::json!({ "text": text }))
+}
+
+#[cfg(unix)]
+fn serve(name: &str, transcript: &Mutex<String>) -> std::io::Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    let path = if std::path::Path::new(name).is_absolute() {
+        std::path::PathBuf::from(name)
+    } else {
+        std::env::temp_dir().join(name)
+    };
+    // A socket left behind by a previous run would make bind fail
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path)?;
+    listener.set_nonblocking(true)?;
+
+    let mut clients = Vec::new();
+    let mut last = transcript.lock().unwrap_or_else(recover_poison).clone();
+    loop {
+        // New clients get the current caption right away
+        while let Ok((mut stream, _)) = listener.accept() {
+            if stream.write_all(caption_line(&last).as_bytes()).is_ok() {
+                clients.push(stream);
+            }
+        }
+        let text = transcript.lock().unwrap_or_else(recover_poison).clone();
+        if text != last {
+            let line = caption_line(&text);
+            clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+            last = text;
+        }
+        thread::sleep(Duration::from_millis(PIPE_POLL_MS));
+    }
+}
+
+#[cfg(windows)]
+fn serve(name: &str, transcript: &Mutex<String>) -> std::io::Result<()> {
+    use std::os::windows::io::FromRawHandle;
+    use windows_sys::Win32::Foundation::{ERROR_PIPE_CONNECTED, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::Storage::FileSystem::PIPE_ACCESS_OUTBOUND;
+    use windows_sys::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PIPE_TYPE_BYTE, PIPE_WAIT,
+    };
+
+    let wide: Vec<u16> = format!(r"\\.\pipe\{name}")
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    loop {
+        // SAFETY: `wide` is a NUL-terminated UTF-16 string that outlives the call
+        let handle = unsafe {
+            CreateNamedPipeW(
+                wide.as_ptr(),
+                PIPE_ACCESS_OUTBOUND,
+                PIPE_TYPE_BYTE | PIPE_WAIT,
+                1,
+                64 * 1024,
+                0,
+                0,
+                std::ptr::null(),
+            )
+        };
+        if handle == INVALID_HANDLE_VALUE {
+            return Err(std::io::Error::last_os_error());
+        }
+        // Blocks until a client opens the pipe. A client that connected between
+        // create and connect reports ERROR_PIPE_CONNECTED, which is fine.
+        // SAFETY: handle is the valid pipe handle created above
+        let connected = unsafe { ConnectNamedPipe(handle, std::ptr::null_mut()) } != 0
+            || std::io::Error::last_os_error().raw_os_error()
+                == Some(ERROR_PIPE_CONNECTED as i32);
+        // SAFETY: the File takes ownership of the handle and closes it on drop
+        let mut pipe = unsafe { std::fs::File::from_raw_handle(handle) };
+        if !connected {
+            continue;
+        }
+
+        let mut last = transcript.lock().unwrap_or_else(recover_poison).clone();
+        if pipe.write_all(caption_line(&last).as_bytes()).is_err() {
+            continue;
+        }
+        loop {
+            let text = transcript.lock().unwrap_or_else(recover_poison).clone();
+            if text != last {
+                if pipe.write_all(caption_line(&text).as_bytes()).is_err() {
+                    break; // client went away; wait for the next one
+                }
+                last = text;
+            }
+            thread::sleep(Duration::from_millis(PIPE_POLL_MS));
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn serve(_name: &str, _transcript: &Mutex<String>) -> std::io::Result<()> {
+    Err(std::io::Error::other("not supported on this platform"))
+}
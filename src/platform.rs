@@ -0,0 +1,94 @@
+// OS window attributes that egui/winit don't expose
+
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+// Keeps the overlay on every virtual desktop/workspace.
+//
+// - Windows: marks it a tool window (WS_EX_TOOLWINDOW), which virtual desktops
+//   show everywhere. Tool windows also don't appear in the taskbar or Alt+Tab.
+// - Linux/X11: sets _NET_WM_DESKTOP to 0xFFFFFFFF (all desktops) via EWMH.
+// - Elsewhere (macOS, Wayland) this is not supported and only logs.
+pub fn set_visible_on_all_workspaces(window: &impl HasWindowHandle, on: bool) {
+    let handle = match window.window_handle() {
+        Ok(h) => h.as_raw(),
+        Err(e) => {
+            eprintln!("No window handle: {e}");
+            return;
+        }
+    };
+    if let Err(e) = apply_all_workspaces(handle, on) {
+        eprintln!("Failed to change workspace pinning: {e}");
+    }
+}
+
+#[cfg(windows)]
+fn apply_all_workspaces(handle: RawWindowHandle, on: bool) -> Result<(), String> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetWindowLongPtrW, SetWindowLongPtrW, GWL_EXSTYLE, WS_EX_APPWINDOW, WS_EX_TOOLWINDOW,
+    };
+    let RawWindowHandle::Win32(h) = handle else {
+        return Err("not a Win32 window".to_string());
+    };
+    let hwnd = h.hwnd.get() as windows_sys::Win32::Foundation::HWND;
+    // SAFETY: hwnd comes from the live eframe window on the UI thread
+    unsafe {
+        let style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        let style = if on {
+            (style | WS_EX_TOOLWINDOW as isize) & !(WS_EX_APPWINDOW as isize)
+        } else {
+            style & !(WS_EX_TOOLWINDOW as isize)
+        };
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, style);
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn apply_all_workspaces(handle: RawWindowHandle, on: bool) -> Result<(), String> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ClientMessageEvent, ConnectionExt, EventMask};
+
+    let window = match handle {
+        RawWindowHandle::Xlib(h) => h.window as u32,
+        RawWindowHandle::Xcb(h) => h.window.get(),
+        _ => return Err("only supported on X11".to_string()),
+    };
+    let (conn, screen) = x11rb::connect(None).map_err(|e| e.to_string())?;
+    let root = conn.setup().roots[screen].root;
+    let atom = |name: &[u8]| -> Result<u32, String> {
+        conn.intern_atom(false, name)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map(|r| r.atom)
+            .map_err(|e| e.to_string())
+    };
+    let wm_desktop = atom(b"_NET_WM_DESKTOP")?;
+    let desktop = if on {
+        0xFFFF_FFFF
+    } else {
+        // Unpin onto the desktop currently shown
+        let current = atom(b"_NET_CURRENT_DESKTOP")?;
+        conn.get_property(false, root, current, x11rb::NONE, 0, 1)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .ok()
+            .and_then(|r| r.value32().and_then(|mut v| v.next()))
+            .unwrap_or(0)
+    };
+    // Source indication 1 = normal application
+    let event = ClientMessageEvent::new(32, window, wm_desktop, [desktop, 1, 0, 0, 0]);
+    conn.send_event(
+        false,
+        root,
+        EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+        event,
+    )
+    .map_err(|e| e.to_string())?;
+    conn.flush().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn apply_all_workspaces(_handle: RawWindowHandle, _on: bool) -> Result<(), String> {
+    Err("not supported on this platform".to_string())
+}
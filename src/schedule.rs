@@ -0,0 +1,66 @@
+use std::sync::{atomic::AtomicBool, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::NaiveTime;
+
+use crate::audio::{recover_poison, WorkerStatus};
+use crate::settings::Settings;
+
+const SCHEDULE_CHECK_SECS: u64 = 5;
+
+fn parse_time(text: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(text.trim(), "%H:%M").ok()
+}
+
+// Whether `now` falls inside the configured window. A window whose end is
+// earlier than its start crosses midnight. None if the schedule is off or the
+// times don't parse.
+pub fn in_window(settings: &Settings, now: NaiveTime) -> Option<bool> {
+    if !settings.schedule_enabled {
+        return None;
+    }
+    let start = parse_time(&settings.schedule_start)?;
+    let end = parse_time(&settings.schedule_end)?;
+    Some(if start <= end {
+        start <= now && now < end
+    } else {
+        now >= start || now < end
+    })
+}
+
+// Short description for the overlay and settings window
+pub fn schedule_status(settings: &Settings, now: NaiveTime) -> Option<String> {
+    if !settings.schedule_enabled {
+        return None;
+    }
+    Some(match in_window(settings, now) {
+        Some(true) => format!("until {}", settings.schedule_end.trim()),
+        Some(false) => format!("starts {}", settings.schedule_start.trim()),
+        None => "invalid time".to_string(),
+    })
+}
+
+// Starts the session when the window opens (or at launch inside it) and stops
+// it when the window closes. Only edges act, so a manual stop inside the
+// window sticks until the next one.
+pub fn start_scheduler(
+    settings: Arc<Mutex<Settings>>,
+    session_active: Arc<AtomicBool>,
+    status: Arc<WorkerStatus>,
+    transcript: Arc<Mutex<String>>,
+) {
+    thread::spawn(move || {
+        let mut was_inside = false;
+        loop {
+            let now = chrono::Local::now().time();
+            let current = settings.lock().unwrap_or_else(recover_poison).clone();
+            let inside = in_window(&current, now).unwrap_or(false);
+            if inside != was_inside {
+                status.set_session_active(&session_active, &transcript, inside);
+                was_inside = inside;
+            }
+            thread::sleep(Duration::from_secs(SCHEDULE_CHECK_SECS));
+        }
+    });
+}
@@ -1,11 +1,33 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
-pub const SILENCE_CHUNKS_TO_END: usize = 10; // ~500ms at 50ms polling
-pub const MAX_PHRASE_SECS: usize = 30;
+pub const POLL_INTERVAL_MS: u64 = 50;
+pub const MIN_PHRASE_SECS: f32 = 0.5; // shorter phrases aren't sent
+pub const REPAINT_INTERVAL_MS: u64 = 200;
+
+// Low-power preset: poll and repaint less often, skip more short phrases
+const LOW_POWER_POLL_INTERVAL_MS: u64 = 200;
+const LOW_POWER_MIN_PHRASE_SECS: f32 = 1.0;
+const LOW_POWER_REPAINT_INTERVAL_MS: u64 = 1000;
+pub const MERGE_FRAGMENT_SECS: f32 = 2.0; // phrases this short may be merged with the next
+pub const MUTE_DETECT_SECS: usize = 2; // exact-zero input this long = muted
+pub const SILENCE_THRESHOLD_MIN: f32 = 0.0005;
+pub const SILENCE_THRESHOLD_MAX: f32 = 0.05;
+pub const SILENCE_THRESHOLD_STEP: f32 = 1.2; // factor per threshold hotkey press
+pub const LOG_FLUSH_SECS: u64 = 5; // session log flush interval in Periodic mode
+pub const DEFAULT_TRANSCRIBE_MODEL: &str = "large-v3";
+
+// Translation system prompt; {target} is replaced with the target language code
+pub const DEFAULT_TRANSLATION_PROMPT: &str = "You are a real-time translator for a scientific presentation. Translate the following spoken text into {target}. Preserve technical and scientific terminology accurately. Output only a single, most probable translation. Print only the translated text and absolutely nothing else—no alternatives, no explanations, no notes, no quotation marks.";
+
+// Source language code that omits the language field so the server detects it
+pub const AUTO_LANGUAGE: &str = "auto";
 
 // Whisper transcription source languages
 pub const SOURCE_LANGUAGES: &[(&str, &str)] = &[
+    (AUTO_LANGUAGE, "Auto-detect"),
     ("ko", "Korean"),
     ("en", "English"),
     ("ja", "Japanese"),
@@ -78,48 +100,459 @@ pub enum DisplayMode {
     Both,
 }
 
+impl DisplayMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DisplayMode::TranslationOnly => "Translation only",
+            DisplayMode::Both => "Both",
+        }
+    }
+
+    // Next variant for the cycle hotkey, wrapping around
+    pub fn next(&self) -> DisplayMode {
+        match self {
+            DisplayMode::TranslationOnly => DisplayMode::Both,
+            DisplayMode::Both => DisplayMode::TranslationOnly,
+        }
+    }
+}
+
+// What to do with phrases that are mostly numbers, equations or units
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum NumericPhraseMode {
+    Translate,    // translate like any other phrase
+    ShowSource,   // skip translation and display the source text
+    KeepVerbatim, // translate, but tell the model to leave numbers/units as-is
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClockMode {
+    WallClock,
+    SessionElapsed,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum CasingMode {
+    Unchanged,
+    SentenceCase,
+}
+
+// How quotation marks around/in a translation are removed
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuoteStripping {
+    Always,    // remove all double-style quotes
+    Never,     // keep the model's output as-is
+    OuterOnly, // remove one pair wrapping the whole translation
+}
+
+// What to do when Whisper returns text that mostly repeats the previous phrase
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum RepeatHandling {
+    Keep,       // treat it as a new phrase
+    Skip,       // drop it entirely
+    SuffixOnly, // keep only the part not in the previous phrase
+}
+
+// Where the REC/settings/close buttons are drawn on the overlay
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ControlsPosition {
+    TopRight,
+    TopLeft,
+    BottomRight,
+    Hidden, // open settings and close via hotkeys
+}
+
+// When the session log is flushed to disk
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum LogFlushMode {
+    EveryLine, // after each entry; nothing is lost on a crash
+    Periodic,  // every LOG_FLUSH_SECS
+    OnClose,   // only when the session ends
+}
+
+// Resampler for the upload copy; sinc costs more CPU but keeps more of the
+// band below 8 kHz and lets less alias into it
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ResampleQuality {
+    Linear, // box average down, linear interpolation up
+    Sinc,   // windowed sinc
+}
+
+// How the clip is sent to the transcription endpoint
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum UploadMode {
+    Multipart,  // OpenAI-style multipart/form-data
+    Base64Json, // {"audio": "<base64 wav>", ...} for JSON-only serverless functions
+}
+
+// Subtitle file written next to each session log, one cue per logged phrase
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SubtitleFormat {
+    Off,
+    Srt,
+    Vtt, // WebVTT
+}
+
+impl SubtitleFormat {
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            SubtitleFormat::Off => None,
+            SubtitleFormat::Srt => Some("srt"),
+            SubtitleFormat::Vtt => Some("vtt"),
+        }
+    }
+}
+
+// Which text goes into each subtitle cue
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SubtitleContent {
+    Source,
+    Translation, // falls back to the source for untranslated phrases
+    Both,        // source line, then translation line
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BarPosition {
+    Top,
+    Bottom,
+}
+
+// A pinned translation for a term; an empty target keeps the term as is
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TermPair {
+    pub source: String,
+    pub target: String,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
+    pub ui_language: String, // settings window language, see i18n::UI_LANGUAGES
     pub api_url: String,
     pub api_key: String, // empty = no auth
+    pub transcribe_model: String, // empty = DEFAULT_TRANSCRIBE_MODEL
+    pub compare_api_url: String, // empty = off; second engine logged alongside the primary
+    pub compare_api_key: String,
+    pub transcribe_timeout_secs: u64,
     pub silence_threshold: f32,
+    pub end_silence_ms: u32,  // pause that ends a phrase
+    pub max_phrase_secs: u32, // longer phrases are cut and sent
     pub language: String,
+    pub candidate_languages: Vec<String>, // 2+ = auto-detect among these; 1 = force it
     pub font_size: f32,
+    pub min_font_size: f32,       // auto-shrink floor
+    pub scroll_on_overflow: bool, // scroll instead of overflowing at the floor
+    pub animate_placeholder: bool, // cycle the idle "..." while listening
+    pub placeholder_step_ms: u32,
+    pub break_long_words: bool,   // wrap at any character, not just word boundaries
+    pub lock_physical_size: bool, // ignore per-monitor scaling after startup
+    pub smooth_font_size: bool,
+    pub font_smoothing: f32, // EMA factor per frame, 0..1
     pub chat_api_url: String,
     pub chat_api_key: String, // empty = no auth
     pub chat_model: String,
+    pub model_shortlist: Vec<String>, // chat models cycled by model_cycle_hotkey
+    pub model_cycle_hotkey: String,
+    pub refine_model: String, // second-pass reviewer, empty = off
+    pub chat_timeout_secs: u64,
+    pub transient_retries: u32, // retries after a 5xx or dropped connection, 0 = off
+    pub max_backoff_secs: u64,        // cap on waiting out a 429 response
+    pub rate_limit_show_source: bool, // show untranslated text instead of waiting
+    pub pool_max_idle_per_host: usize, // idle connections kept per API host
+    pub pool_idle_timeout_secs: u64,   // how long an idle connection is kept
+    pub http2: bool,                   // offer HTTP/2 (falls back to 1.1)
     pub target_language: String, // empty = no translation
+    pub translation_prompts: BTreeMap<String, String>, // per-target overrides of the prompt
+    pub term_pairs: Vec<TermPair>, // pinned term translations, added to the prompt
+    pub translation_context_pairs: u32, // recent phrases sent as context, 0 = none
+    pub history_includes_translation: bool, // false = prior source text only
+    pub translation_casing: CasingMode,
+    pub strip_quotes: QuoteStripping,
+    pub translate_on_sentence: bool, // buffer fragments until sentence-end punctuation
+    pub sentence_timeout_ms: u32,    // translate the buffer anyway after this long
+    pub glossary: String, // comma/newline separated; biases transcription and translation
     pub display_mode: DisplayMode,
+    pub rolling_phrases: u32, // captions kept on screen, newest at the bottom; 1 = latest only
+    pub display_mode_hotkey: String, // e.g. "Ctrl+D", empty = disabled
+    pub caption_prefix: String, // on-screen only, {lang} = displayed language code
+    pub caption_suffix: String,
+    pub learner_mode: bool, // obscure the translation line until revealed
+    pub reveal_hotkey: String,
+    pub emphasis_hotkey: String,
+    pub emphasis_scale: f32, // font size multiplier for an emphasized caption
+    pub emphasis_color: [u8; 3],
+    pub retro_buffer_secs: u32, // recent audio kept for saving after the fact, 0 = off
+    pub save_retro_hotkey: String,
+    pub settings_hotkey: String,
+    pub threshold_up_hotkey: String,
+    pub threshold_down_hotkey: String,
+    pub close_hotkey: String,
+    pub controls_position: ControlsPosition,
     pub opacity: u8,             // 0=transparent, 255=opaque
+    pub reactive_opacity: bool,  // fade toward idle_opacity during silence
+    pub idle_opacity: u8,
+    pub text_color: [u8; 3],
+    pub confidence_coloring: bool, // tint captions by transcription confidence
+    pub confidence_high: f32,      // green at or above
+    pub confidence_low: f32,       // yellow at or above, red below
+    pub chroma_key_mode: bool,   // solid key color instead of the black background
+    pub chroma_key_color: [u8; 3],
+    pub hide_startup_frames: bool, // draw nothing until the window has settled
+    pub lower_third: bool,       // background and caption only in a bottom band
+    pub band_height: f32,
     pub input_device: String,    // empty = system default
+    pub audio_host: String,      // cpal host name, empty = platform default
+    pub force_sample_rate: u32,  // capture rate in Hz, 0 = device default
+    pub resample_quality: ResampleQuality,
+    pub normalize_audio: bool,   // peak-normalize each phrase before upload
+    pub agc_enabled: bool,       // level the input before VAD and encoding
+    pub agc_target: f32,         // envelope level the AGC aims for
+    pub agc_attack_ms: u32,      // how fast gain drops on loud input
+    pub agc_release_ms: u32,     // how fast gain recovers on quieter input
+    pub normalize_target: f32,   // peak level, fraction of full scale
+    pub numeric_phrase_mode: NumericPhraseMode,
+    pub numeric_ratio_threshold: f32, // fraction of numeric/symbol tokens
+    pub upload_filename: String,      // extension always the codec's, empty = audio.<ext>
+    pub upload_mode: UploadMode,
+    pub detect_mute: bool,
+    pub capture_only_when_visible: bool, // drop speech while the window is minimized or hidden
+    pub show_clock: bool,
+    pub clock_mode: ClockMode,
+    pub clock_format: String, // chrono strftime format
+    pub clock_corner: Corner,
+    pub cleanup_enabled: bool,
+    pub cleanup_prompt: String,
+    #[serde(alias = "filter_punctuation_only")]
+    pub filter_nonlexical: bool, // drop results without letters/digits (".", "♪", emoji)
+    pub extra_noise_chars: String, // characters treated as noise even if letters, e.g. "ㅋ"
+    pub show_status_bar: bool,
+    pub status_bar_position: BarPosition,
+    pub show_processing_indicator: bool,
+    pub show_upload_progress: bool,
+    pub auto_start_session: bool,
+    pub startup_grace_secs: u32, // ignore speech this long after a session starts
+    pub max_session_files: usize, // oldest session logs beyond this are deleted, 0 = keep all
+    pub schedule_enabled: bool,
+    pub schedule_start: String, // HH:MM local time
+    pub schedule_end: String,   // earlier than start = crosses midnight
+    pub overlap_carry_ms: u32, // audio carried into the next phrase on a forced cut
+    pub repeat_handling: RepeatHandling,
+    pub repeat_similarity: f32, // overlap ratio at which a phrase counts as a repeat
+    pub merge_fragments: bool,
+    pub merge_gap_ms: u32,
+    pub queue_captions: bool,       // hold captions that arrive while one is still fresh
+    pub min_display_ms: u32,        // minimum time a queued caption stays on screen
+    pub max_queued_captions: usize, // oldest pending captions are dropped past this
+    pub log_flush_mode: LogFlushMode,
+    pub session_rotate_mins: u32, // start a new log part after this long, 0 = never
+    pub subtitle_format: SubtitleFormat,
+    pub subtitle_content: SubtitleContent,
+    pub subtitle_offset_ms: i32, // added to every cue time, negative = earlier
+    pub smart_merge_sentences: bool, // join log entries that continue a sentence
+    pub mark_turns: bool,            // write a separator to the log after long pauses
+    pub turn_gap_secs: f32,
+    pub all_workspaces: bool, // keep the overlay on every virtual desktop
+    pub mouse_passthrough: bool, // clicks go to the window underneath the overlay
+    pub passthrough_hotkey: String,
+    pub global_session_hotkey: String, // system-wide, empty = disabled
+    pub global_visibility_hotkey: String,
+    pub global_passthrough_hotkey: String,
+    pub skip_taskbar: bool,   // applied at startup
+    pub no_focus: bool,       // don't take focus when shown, applied at startup
+    pub low_power: bool, // slower polling/repaint and longer minimum phrases
+    pub debug_logging: bool,    // append API request records to debug.log
+    pub debug_log_bodies: bool, // include request/response bodies
+    pub control_api_enabled: bool,
+    pub control_api_bind: String, // host:port, localhost-only by default
+    pub pipe_name: String,        // local caption pipe/socket, empty = off
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
+            ui_language: "en".to_string(),
             api_url: "https://api.openai.com/v1/audio/transcriptions".to_string(),
             api_key: String::new(),
+            transcribe_model: DEFAULT_TRANSCRIBE_MODEL.to_string(),
+            compare_api_url: String::new(),
+            compare_api_key: String::new(),
+            transcribe_timeout_secs: 30,
             silence_threshold: 0.003,
+            end_silence_ms: 500,
+            max_phrase_secs: 30,
             language: "ko".to_string(),
+            candidate_languages: Vec::new(),
             font_size: 60.0,
+            min_font_size: 12.0,
+            scroll_on_overflow: false,
+            animate_placeholder: true,
+            placeholder_step_ms: 500,
+            break_long_words: false,
+            lock_physical_size: false,
+            smooth_font_size: false,
+            font_smoothing: 0.2,
             chat_api_url: "https://api.openai.com/v1/chat/completions".to_string(),
             chat_api_key: String::new(),
             chat_model: "gpt-4o".to_string(),
+            model_shortlist: Vec::new(),
+            model_cycle_hotkey: "Ctrl+M".to_string(),
+            refine_model: String::new(),
+            chat_timeout_secs: 30,
+            transient_retries: 2,
+            max_backoff_secs: 30,
+            rate_limit_show_source: true,
+            pool_max_idle_per_host: 2,
+            pool_idle_timeout_secs: 90,
+            http2: true,
             target_language: "en".to_string(),
+            translation_prompts: BTreeMap::new(),
+            term_pairs: Vec::new(),
+            translation_context_pairs: 3,
+            history_includes_translation: true,
+            translation_casing: CasingMode::Unchanged,
+            strip_quotes: QuoteStripping::OuterOnly,
+            translate_on_sentence: false,
+            sentence_timeout_ms: 4000,
+            glossary: String::new(),
             display_mode: DisplayMode::TranslationOnly,
+            rolling_phrases: 1,
+            display_mode_hotkey: "Ctrl+D".to_string(),
+            caption_prefix: String::new(),
+            caption_suffix: String::new(),
+            learner_mode: false,
+            reveal_hotkey: "Space".to_string(),
+            emphasis_hotkey: "Ctrl+E".to_string(),
+            emphasis_scale: 1.3,
+            emphasis_color: [255, 210, 80],
+            retro_buffer_secs: 0,
+            save_retro_hotkey: "Ctrl+R".to_string(),
+            settings_hotkey: "F2".to_string(),
+            threshold_up_hotkey: "Ctrl+Up".to_string(),
+            threshold_down_hotkey: "Ctrl+Down".to_string(),
+            close_hotkey: "Ctrl+Q".to_string(),
+            controls_position: ControlsPosition::TopRight,
             opacity: 200,
+            reactive_opacity: false,
+            idle_opacity: 60,
+            text_color: [255, 255, 255],
+            confidence_coloring: false,
+            confidence_high: 0.75,
+            confidence_low: 0.5,
+            chroma_key_mode: false,
+            chroma_key_color: [0, 255, 0],
+            hide_startup_frames: true,
+            lower_third: false,
+            band_height: 160.0,
             input_device: String::new(),
+            audio_host: String::new(),
+            force_sample_rate: 0,
+            resample_quality: ResampleQuality::Linear,
+            normalize_audio: false,
+            agc_enabled: false,
+            agc_target: 0.2,
+            agc_attack_ms: 10,
+            agc_release_ms: 800,
+            normalize_target: 0.9,
+            numeric_phrase_mode: NumericPhraseMode::Translate,
+            numeric_ratio_threshold: 0.5,
+            upload_filename: String::new(),
+            upload_mode: UploadMode::Multipart,
+            detect_mute: false,
+            capture_only_when_visible: false,
+            show_clock: false,
+            clock_mode: ClockMode::WallClock,
+            clock_format: "%H:%M:%S".to_string(),
+            clock_corner: Corner::BottomRight,
+            cleanup_enabled: false,
+            cleanup_prompt: "Clean up the following speech transcript. Remove filler words, false starts and repetitions, and fix punctuation and capitalization. Do not translate, summarize or add anything. Print only the cleaned text.".to_string(),
+            filter_nonlexical: true,
+            extra_noise_chars: String::new(),
+            show_status_bar: false,
+            status_bar_position: BarPosition::Top,
+            show_processing_indicator: false,
+            show_upload_progress: false,
+            auto_start_session: false,
+            startup_grace_secs: 0,
+            max_session_files: 0,
+            schedule_enabled: false,
+            schedule_start: "09:00".to_string(),
+            schedule_end: "17:00".to_string(),
+            overlap_carry_ms: 0,
+            repeat_handling: RepeatHandling::Keep,
+            repeat_similarity: 0.8,
+            merge_fragments: false,
+            merge_gap_ms: 400,
+            queue_captions: false,
+            min_display_ms: 2000,
+            max_queued_captions: 3,
+            log_flush_mode: LogFlushMode::EveryLine,
+            session_rotate_mins: 0,
+            subtitle_format: SubtitleFormat::Off,
+            subtitle_content: SubtitleContent::Both,
+            subtitle_offset_ms: 0,
+            smart_merge_sentences: false,
+            mark_turns: false,
+            turn_gap_secs: 5.0,
+            all_workspaces: false,
+            mouse_passthrough: false,
+            passthrough_hotkey: "Ctrl+T".to_string(),
+            global_session_hotkey: "Ctrl+Alt+C".to_string(),
+            global_visibility_hotkey: "Ctrl+Alt+H".to_string(),
+            global_passthrough_hotkey: "Ctrl+Alt+P".to_string(),
+            skip_taskbar: false,
+            no_focus: false,
+            low_power: false,
+            debug_logging: false,
+            debug_log_bodies: false,
+            control_api_enabled: false,
+            control_api_bind: "127.0.0.1:8787".to_string(),
+            pipe_name: String::new(),
         }
     }
 }
 
+// Display name for a language code, falling back to the code itself
+pub fn language_name<'a>(languages: &[(&str, &'a str)], code: &'a str) -> &'a str {
+    languages
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+        .unwrap_or(code)
+}
+
+// Set once from `--config <path>` before settings are first loaded
+static CONFIG_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_config_path(path: PathBuf) {
+    let _ = CONFIG_OVERRIDE.set(path);
+}
+
 fn config_path() -> PathBuf {
+    if let Some(path) = CONFIG_OVERRIDE.get() {
+        return path.clone();
+    }
     let exe = std::env::current_exe().unwrap_or_default();
     exe.parent()
         .unwrap_or(std::path::Path::new("."))
         .join("settings.yml")
 }
 
+pub fn debug_log_path() -> PathBuf {
+    let exe = std::env::current_exe().unwrap_or_default();
+    exe.parent()
+        .unwrap_or(std::path::Path::new("."))
+        .join("debug.log")
+}
+
 pub fn sessions_dir() -> PathBuf {
     let exe = std::env::current_exe().unwrap_or_default();
     let dir = exe
@@ -132,7 +565,129 @@ pub fn sessions_dir() -> PathBuf {
     dir
 }
 
+// Session a log or subtitle file belongs to: "session_<time>" for
+// session_<time>.txt, session_<time>_part2.txt and session_<time>.srt alike
+fn session_stem(name: &str) -> &str {
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    match stem.rsplit_once("_part") {
+        Some((prefix, part)) if part.parse::<u32>().is_ok() => prefix,
+        _ => stem,
+    }
+}
+
+// Deletes the oldest session logs (session_*.txt, including split parts) beyond
+// `max` by modified time, and a session's .srt/.vtt subtitles once none of its
+// log parts are kept. Other files in the folder are never touched.
+pub fn prune_session_files(max: usize) {
+    if max == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(sessions_dir()) else {
+        return;
+    };
+    let mut logs: Vec<(std::time::SystemTime, PathBuf)> = Vec::new();
+    let mut subtitles: Vec<PathBuf> = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !name.starts_with("session_") {
+            continue;
+        }
+        if name.ends_with(".txt") {
+            if let Some(modified) = entry.metadata().ok().and_then(|m| m.modified().ok()) {
+                logs.push((modified, entry.path()));
+            }
+        } else if name.ends_with(".srt") || name.ends_with(".vtt") {
+            subtitles.push(entry.path());
+        }
+    }
+    if logs.len() <= max {
+        return;
+    }
+    logs.sort();
+    let (old, kept) = logs.split_at(logs.len() - max);
+    for (_, path) in old {
+        if let Err(e) = std::fs::remove_file(path) {
+            eprintln!("Failed to remove old session log {}: {e}", path.display());
+        }
+    }
+
+    // A session's subtitle file goes once none of its log parts are left
+    let stem_of = |path: &PathBuf| {
+        session_stem(&path.file_name().unwrap_or_default().to_string_lossy()).to_string()
+    };
+    let kept_sessions: Vec<String> = kept.iter().map(|(_, path)| stem_of(path)).collect();
+    for path in subtitles {
+        if !kept_sessions.contains(&stem_of(&path))
+            && let Err(e) = std::fs::remove_file(&path)
+        {
+            eprintln!("Failed to remove old subtitle file {}: {e}", path.display());
+        }
+    }
+}
+
 impl Settings {
+    // The language to force on the transcription endpoint, None in auto-detect
+    pub fn forced_language(&self) -> Option<&str> {
+        (self.language != AUTO_LANGUAGE).then_some(self.language.as_str())
+    }
+
+    pub fn transcribe_model(&self) -> &str {
+        match self.transcribe_model.trim() {
+            "" => DEFAULT_TRANSCRIBE_MODEL,
+            model => model,
+        }
+    }
+
+    pub fn poll_interval_ms(&self) -> u64 {
+        if self.low_power {
+            LOW_POWER_POLL_INTERVAL_MS
+        } else {
+            POLL_INTERVAL_MS
+        }
+    }
+
+    // Chunks of sustained silence that end a phrase, keeping the same
+    // end_silence_ms regardless of the poll interval
+    pub fn silence_chunks_to_end(&self) -> usize {
+        (self.end_silence_ms as u64).div_ceil(self.poll_interval_ms()).max(1) as usize
+    }
+
+    pub fn min_phrase_secs(&self) -> f32 {
+        if self.low_power {
+            LOW_POWER_MIN_PHRASE_SECS
+        } else {
+            MIN_PHRASE_SECS
+        }
+    }
+
+    pub fn repaint_interval_ms(&self) -> u64 {
+        if self.low_power {
+            LOW_POWER_REPAINT_INTERVAL_MS
+        } else {
+            REPAINT_INTERVAL_MS
+        }
+    }
+
+    // Shortlisted model after the current chat model, wrapping around
+    pub fn next_shortlisted_model(&self) -> Option<String> {
+        let models: Vec<&str> = self
+            .model_shortlist
+            .iter()
+            .map(|m| m.trim())
+            .filter(|m| !m.is_empty())
+            .collect();
+        let next = match models.iter().position(|m| *m == self.chat_model) {
+            Some(i) => models.get(i + 1).or(models.first()),
+            None => models.first(),
+        };
+        next.map(|m| m.to_string())
+    }
+
+    // Both mode without a target language can only ever show the source
+    pub fn missing_translation_target(&self) -> bool {
+        self.display_mode == DisplayMode::Both && self.target_language.is_empty()
+    }
+
     pub fn load() -> Self {
         let path = config_path();
         if path.exists() {
@@ -153,10 +708,30 @@ impl Settings {
 
     pub fn save(&self) {
         let path = config_path();
-        if let Ok(yaml) = serde_yaml::to_string(self) {
-            if let Err(e) = std::fs::write(&path, yaml) {
-                eprintln!("Failed to save settings: {e}");
-            }
+        if let Ok(yaml) = serde_yaml::to_string(self)
+            && let Err(e) = std::fs::write(&path, yaml)
+        {
+            eprintln!("Failed to save settings: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_stem_groups_log_parts_and_subtitles() {
+        let stem = "session_2026-10-15_09-30-00";
+        for name in [
+            "session_2026-10-15_09-30-00.txt",
+            "session_2026-10-15_09-30-00_part2.txt",
+            "session_2026-10-15_09-30-00_part12.txt",
+            "session_2026-10-15_09-30-00.srt",
+            "session_2026-10-15_09-30-00.vtt",
+        ] {
+            assert_eq!(session_stem(name), stem, "{name}");
         }
+        assert_eq!(session_stem("session_2026-10-15_party.txt"), "session_2026-10-15_party");
     }
 }
@@ -0,0 +1,482 @@
+use crate::settings::{CasingMode, QuoteStripping, Settings};
+use std::collections::HashMap;
+
+// Fraction of whitespace-separated tokens that are numbers, equations or units
+// (anything containing a digit or no letters at all, e.g. "10^-9", "=", "3.5nm").
+pub fn numeric_ratio(text: &str) -> f32 {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    if tokens.is_empty() {
+        return 0.0;
+    }
+    let numeric = tokens
+        .iter()
+        .filter(|t| {
+            t.chars().any(|c| c.is_ascii_digit()) || !t.chars().any(char::is_alphabetic)
+        })
+        .count();
+    numeric as f32 / tokens.len() as f32
+}
+
+// Whether a transcription carries no actual words. With the filter enabled,
+// results with no letters or digits in any script (e.g. ".", "…", "♪" or an
+// emoji) count as empty, as do results made only of the extra noise characters.
+pub fn is_blank_text(text: &str, settings: &Settings) -> bool {
+    if !settings.filter_nonlexical {
+        return text.trim().is_empty();
+    }
+    text.chars()
+        .all(|c| !c.is_alphanumeric() || settings.extra_noise_chars.contains(c))
+}
+
+const ABBREVIATIONS: &[&str] = &["vs", "cf", "fig", "eq", "approx", "dr", "prof", "mr", "mrs"];
+
+// Whether a word ending in '.' is an abbreviation rather than a sentence end:
+// dotted ones like "e.g." or "i.e." and a few common short forms
+fn is_abbreviation(word: &str) -> bool {
+    let stem = word.trim_end_matches('.');
+    stem.contains('.') || ABBREVIATIONS.iter().any(|a| a.eq_ignore_ascii_case(stem))
+}
+
+// Normalizes casing of a translation so consecutive captions look uniform
+pub fn apply_casing(text: &str, mode: &CasingMode) -> String {
+    match mode {
+        CasingMode::Unchanged => text.to_string(),
+        CasingMode::SentenceCase => {
+            // Uppercase the first letter of each sentence, leave the rest alone.
+            // A terminator only ends a sentence when followed by whitespace or
+            // the end, and not after an abbreviation, so "3.5 nm" and "e.g. the"
+            // stay as they are
+            let mut out = String::with_capacity(text.len());
+            let mut sentence_start = true;
+            let mut word = String::new();
+            let mut chars = text.chars().peekable();
+            while let Some(c) = chars.next() {
+                if sentence_start && c.is_alphabetic() {
+                    out.extend(c.to_uppercase());
+                    sentence_start = false;
+                } else {
+                    out.push(c);
+                }
+                if c.is_whitespace() {
+                    word.clear();
+                } else {
+                    word.push(c);
+                }
+                if matches!(c, '.' | '!' | '?')
+                    && chars.peek().is_none_or(|next| next.is_whitespace())
+                    && !(c == '.' && is_abbreviation(&word))
+                {
+                    sentence_start = true;
+                }
+            }
+            out
+        }
+    }
+}
+
+const QUOTE_PAIRS: &[(char, char)] = &[
+    ('"', '"'),
+    ('\'', '\''),
+    ('“', '”'),
+    ('‘', '’'),
+    ('「', '」'),
+    ('『', '』'),
+    ('«', '»'),
+];
+
+const DOUBLE_QUOTES: &str = "\"“”„「」『』«»";
+
+// Removes quotation marks models sometimes add around a translation
+pub fn strip_quotes(text: &str, mode: &QuoteStripping) -> String {
+    match mode {
+        QuoteStripping::Never => text.to_string(),
+        QuoteStripping::OuterOnly => {
+            let trimmed = text.trim();
+            for &(open, close) in QUOTE_PAIRS {
+                if let Some(inner) = trimmed
+                    .strip_prefix(open)
+                    .and_then(|t| t.strip_suffix(close))
+                {
+                    return inner.trim().to_string();
+                }
+            }
+            text.to_string()
+        }
+        // Single quotes double as apostrophes, so only double-style marks go
+        QuoteStripping::Always => text.chars().filter(|c| !DOUBLE_QUOTES.contains(*c)).collect(),
+    }
+}
+
+// Whether text ends a sentence, ignoring trailing quotes and brackets
+pub fn ends_sentence(text: &str) -> bool {
+    text.trim_end()
+        .trim_end_matches(['"', '\'', '”', '’', ')', '」', '』'])
+        .ends_with(['.', '!', '?', '…', '。', '！', '？'])
+}
+
+// Byte length of `needle` at the start of `hay` when compared case-insensitively
+fn match_ignore_case(hay: &str, needle: &str) -> Option<usize> {
+    let mut hay_chars = hay.char_indices();
+    for n in needle.chars() {
+        let (_, h) = hay_chars.next()?;
+        if !h.to_lowercase().eq(n.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(hay_chars.next().map(|(i, _)| i).unwrap_or(hay.len()))
+}
+
+// Rewrites whole-word, case-insensitive occurrences of each term (e.g. "DNA",
+// "CRISPR") to its canonical spelling
+pub fn apply_canonical_terms<'a>(
+    text: &str,
+    terms: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let mut out = text.to_string();
+    for term in terms {
+        if term.is_empty() {
+            continue;
+        }
+        let mut result = String::with_capacity(out.len());
+        let mut prev: Option<char> = None;
+        let mut i = 0;
+        while i < out.len() {
+            let rest = &out[i..];
+            let c = rest.chars().next().unwrap();
+            if !prev.is_some_and(char::is_alphanumeric)
+                && let Some(len) = match_ignore_case(rest, term)
+                && !rest[len..].chars().next().is_some_and(char::is_alphanumeric)
+            {
+                result.push_str(term);
+                prev = term.chars().last();
+                i += len;
+                continue;
+            }
+            result.push(c);
+            prev = Some(c);
+            i += c.len_utf8();
+        }
+        out = result;
+    }
+    out
+}
+
+// Terms from the comma/newline separated `glossary` setting, which also give
+// their canonical spellings
+pub fn glossary_terms(settings: &Settings) -> impl Iterator<Item = &str> {
+    settings
+        .glossary
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+}
+
+// Glossary terms as one list for a prompt, or None when there are none
+pub fn glossary_prompt(settings: &Settings) -> Option<String> {
+    let terms: Vec<&str> = glossary_terms(settings).collect();
+    (!terms.is_empty()).then(|| terms.join(", "))
+}
+
+// Prompt sentence pinning term translations, e.g.
+// `Always translate "kinase" as "키나아제"; leave "CRISPR" untranslated.`
+pub fn term_pair_prompt(settings: &Settings) -> Option<String> {
+    let rules: Vec<String> = settings
+        .term_pairs
+        .iter()
+        .filter(|pair| !pair.source.trim().is_empty())
+        .map(|pair| match pair.target.trim() {
+            "" => format!("leave \"{}\" untranslated", pair.source.trim()),
+            target => format!("always translate \"{}\" as \"{target}\"", pair.source.trim()),
+        })
+        .collect();
+    let sentence = rules.join("; ");
+    let mut chars = sentence.chars();
+    let first = chars.next()?;
+    Some(format!("{}{}.", first.to_uppercase(), chars.as_str()))
+}
+
+// Lowercased alphanumeric core of a word, so "Word," matches "word"
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+const MAX_OVERLAP_WORDS: usize = 6;
+const MAX_OVERLAP_CHARS: usize = 12;
+
+// Removes the start of `next` that repeats the end of `prev`. Used when a
+// forced cut carries audio over, so both clips may contain the boundary words.
+// Compares whole words for spaced scripts and characters otherwise (CJK).
+pub fn strip_overlap(prev: &str, next: &str) -> String {
+    let prev_words: Vec<String> = prev.split_whitespace().map(normalize_word).collect();
+    let next_words: Vec<&str> = next.split_whitespace().collect();
+    if prev_words.len() > 1 && next_words.len() > 1 {
+        let max = MAX_OVERLAP_WORDS.min(prev_words.len()).min(next_words.len());
+        for k in (1..=max).rev() {
+            let tail = &prev_words[prev_words.len() - k..];
+            let head = next_words[..k].iter().map(|w| normalize_word(w));
+            if head.eq(tail.iter().cloned()) {
+                return next_words[k..].join(" ");
+            }
+        }
+        return next.to_string();
+    }
+
+    let prev_chars: Vec<char> = prev.chars().filter(|c| c.is_alphanumeric()).collect();
+    let next_chars: Vec<(usize, char)> = next.char_indices().collect();
+    let max = MAX_OVERLAP_CHARS.min(prev_chars.len()).min(next_chars.len());
+    for k in (2..=max).rev() {
+        let tail = &prev_chars[prev_chars.len() - k..];
+        if next_chars[..k].iter().map(|(_, c)| *c).eq(tail.iter().copied()) {
+            let cut = next_chars.get(k).map(|(i, _)| *i).unwrap_or(next.len());
+            return next[cut..]
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .to_string();
+        }
+    }
+    next.to_string()
+}
+
+// Character bigrams of the lowercased alphanumeric text, so the comparison
+// works the same for spaced and unspaced scripts
+fn bigrams(text: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = text
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+// Fraction of `next` already contained in `prev` (0.0 = all new, 1.0 = repeat)
+pub fn overlap_ratio(prev: &str, next: &str) -> f32 {
+    let next_grams = bigrams(next);
+    if next_grams.is_empty() {
+        return 0.0;
+    }
+    let mut available: HashMap<(char, char), usize> = HashMap::new();
+    for g in bigrams(prev) {
+        *available.entry(g).or_default() += 1;
+    }
+    let shared = next_grams
+        .iter()
+        .filter(|g| match available.get_mut(g) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                true
+            }
+            _ => false,
+        })
+        .count();
+    shared as f32 / next_grams.len() as f32
+}
+
+// The part of `next` after its longest prefix that also appears in `prev`,
+// e.g. prev "we measured it", next "we measured it twice" -> "twice"
+pub fn novel_suffix(prev: &str, next: &str) -> String {
+    let prev_words: Vec<String> = prev.split_whitespace().map(normalize_word).collect();
+    let next_words: Vec<&str> = next.split_whitespace().collect();
+    if prev_words.len() > 1 && next_words.len() > 1 {
+        let normalized: Vec<String> = next_words.iter().map(|w| normalize_word(w)).collect();
+        let k = (1..=normalized.len())
+            .rev()
+            .find(|&k| prev_words.windows(k).any(|w| w == &normalized[..k]))
+            .unwrap_or(0);
+        return next_words[k..].join(" ");
+    }
+
+    let prev_norm: String = prev.chars().filter(|c| c.is_alphanumeric()).collect();
+    let next_chars: Vec<(usize, char)> = next.char_indices().collect();
+    let mut cut = 0;
+    let mut prefix = String::new();
+    for (i, c) in &next_chars {
+        if c.is_alphanumeric() {
+            prefix.push(*c);
+            if !prev_norm.contains(&prefix) {
+                break;
+            }
+        }
+        cut = i + c.len_utf8();
+    }
+    next[cut..]
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_quotes_never_keeps_text() {
+        assert_eq!(strip_quotes("\"Hello\"", &QuoteStripping::Never), "\"Hello\"");
+        assert_eq!(strip_quotes("Hello", &QuoteStripping::Never), "Hello");
+    }
+
+    #[test]
+    fn strip_quotes_outer_only() {
+        let mode = QuoteStripping::OuterOnly;
+        assert_eq!(strip_quotes("\"Hello\"", &mode), "Hello");
+        assert_eq!(strip_quotes(" “Hello” ", &mode), "Hello");
+        assert_eq!(strip_quotes("「안녕」", &mode), "안녕");
+        assert_eq!(strip_quotes("Hello", &mode), "Hello");
+        // Mismatched pair is left alone
+        assert_eq!(strip_quotes("\"Hello'", &mode), "\"Hello'");
+        // Quotes inside the text are kept
+        assert_eq!(strip_quotes("He said \"yes\" twice", &mode), "He said \"yes\" twice");
+    }
+
+    #[test]
+    fn strip_quotes_always() {
+        let mode = QuoteStripping::Always;
+        assert_eq!(strip_quotes("\"Hello\"", &mode), "Hello");
+        assert_eq!(strip_quotes("He said \"yes\" twice", &mode), "He said yes twice");
+        assert_eq!(strip_quotes("“Hello'", &mode), "Hello'");
+        assert_eq!(strip_quotes("Hello", &mode), "Hello");
+        // Apostrophes survive
+        assert_eq!(strip_quotes("It's the cell's \"core\"", &mode), "It's the cell's core");
+    }
+
+    #[test]
+    fn overlap_ratio_full_repeat() {
+        let ratio = overlap_ratio("We measured it twice.", "we measured it twice");
+        assert!((ratio - 1.0).abs() < 1e-6, "{ratio}");
+    }
+
+    #[test]
+    fn overlap_ratio_new_text() {
+        assert!(overlap_ratio("the enzyme binds", "quantum xyz") < 0.2);
+        // Unspaced scripts compare by character pairs too
+        let ratio = overlap_ratio("효소가 결합합니다", "효소가 결합합니다");
+        assert!((ratio - 1.0).abs() < 1e-6, "{ratio}");
+        assert!(overlap_ratio("효소가 결합합니다", "온도를 높였습니다") < 0.2);
+    }
+
+    #[test]
+    fn overlap_ratio_empty_input() {
+        assert_eq!(overlap_ratio("anything", ""), 0.0);
+        assert_eq!(overlap_ratio("", "new words"), 0.0);
+        assert_eq!(overlap_ratio("", ""), 0.0);
+    }
+
+    #[test]
+    fn novel_suffix_after_repeat() {
+        assert_eq!(novel_suffix("we measured it", "we measured it twice"), "twice");
+        assert_eq!(novel_suffix("We measured it.", "we measured it twice"), "twice");
+        assert_eq!(novel_suffix("we measured it", "something else"), "something else");
+        assert_eq!(novel_suffix("we measured it", "we measured it"), "");
+    }
+
+    #[test]
+    fn novel_suffix_unspaced() {
+        assert_eq!(novel_suffix("효소가결합", "효소가결합합니다"), "합니다");
+        assert_eq!(novel_suffix("酶结合", "酶结合很快"), "很快");
+    }
+
+    #[test]
+    fn novel_suffix_empty_input() {
+        assert_eq!(novel_suffix("", "new words"), "new words");
+        assert_eq!(novel_suffix("old words", ""), "");
+    }
+
+    #[test]
+    fn strip_overlap_words() {
+        assert_eq!(strip_overlap("the enzyme binds", "binds the substrate"), "the substrate");
+        assert_eq!(
+            strip_overlap("so one two three four five six", "one two three four five six more"),
+            "more"
+        );
+        // Longer repeats than the maximum aren't looked for
+        assert_eq!(
+            strip_overlap(
+                "one two three four five six seven",
+                "one two three four five six seven more"
+            ),
+            "one two three four five six seven more"
+        );
+    }
+
+    #[test]
+    fn strip_overlap_none() {
+        assert_eq!(strip_overlap("the enzyme binds", "then it folds"), "then it folds");
+        assert_eq!(strip_overlap("", "then it folds"), "then it folds");
+    }
+
+    #[test]
+    fn strip_overlap_ignores_case_and_punctuation() {
+        assert_eq!(strip_overlap("a single Word,", "word and more"), "and more");
+        assert_eq!(strip_overlap("it binds the Substrate.", "the substrate, then"), "then");
+    }
+
+    #[test]
+    fn strip_overlap_characters() {
+        assert_eq!(strip_overlap("효소가 결합", "결합합니다"), "합니다");
+        assert_eq!(strip_overlap("酶很快结合", "结合，然后"), "然后");
+        // A single shared character isn't enough
+        assert_eq!(strip_overlap("酶结合", "合成"), "合成");
+    }
+
+    fn filter_settings(extra_noise_chars: &str) -> Settings {
+        Settings {
+            filter_nonlexical: true,
+            extra_noise_chars: extra_noise_chars.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn blank_text_drops_junk() {
+        let settings = filter_settings("");
+        for junk in ["", " ", ".", "…", "♪", "♪ ♪", "😀", "👍🎵", " ... "] {
+            assert!(is_blank_text(junk, &settings), "{junk:?}");
+        }
+    }
+
+    #[test]
+    fn blank_text_keeps_words() {
+        let settings = filter_settings("");
+        for word in ["a", "是", "네", "42", "Hello.", "♪ 네 ♪"] {
+            assert!(!is_blank_text(word, &settings), "{word:?}");
+        }
+    }
+
+    #[test]
+    fn blank_text_extra_noise_chars() {
+        let settings = filter_settings("嗯");
+        assert!(is_blank_text("嗯", &settings));
+        assert!(is_blank_text("嗯…", &settings));
+        assert!(!is_blank_text("嗯是", &settings));
+    }
+
+    #[test]
+    fn blank_text_filter_off() {
+        let settings = Settings {
+            filter_nonlexical: false,
+            extra_noise_chars: "嗯".to_string(),
+            ..Default::default()
+        };
+        assert!(is_blank_text("  ", &settings));
+        assert!(!is_blank_text(".", &settings));
+        assert!(!is_blank_text("♪", &settings));
+        assert!(!is_blank_text("嗯", &settings));
+    }
+
+    #[test]
+    fn sentence_case_capitalizes_sentences() {
+        let mode = CasingMode::SentenceCase;
+        assert_eq!(apply_casing("hello. how are you? fine!", &mode), "Hello. How are you? Fine!");
+        assert_eq!(apply_casing("already Fine", &CasingMode::Unchanged), "already Fine");
+    }
+
+    #[test]
+    fn sentence_case_keeps_decimals_and_abbreviations() {
+        let mode = CasingMode::SentenceCase;
+        assert_eq!(apply_casing("the gap is 3.5 nm wide", &mode), "The gap is 3.5 nm wide");
+        assert_eq!(apply_casing("use e.g. the buffer", &mode), "Use e.g. the buffer");
+        assert_eq!(apply_casing("i.e. the same. next", &mode), "I.e. the same. Next");
+        assert_eq!(apply_casing("see fig. 2 and eq. 3", &mode), "See fig. 2 and eq. 3");
+        assert_eq!(apply_casing("see fig.2a for details", &mode), "See fig.2a for details");
+    }
+}